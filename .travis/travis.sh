@@ -11,15 +11,23 @@ export PATH="$HOME/.cargo/bin:$PATH"
 cargo build --all
 
 if [[ `git status --porcelain` ]]; then
-  echo "The build step produced some changes that are not versioned"
+  echo "The build step produced some changes that are not versioned, README.rst or Ontology.kt may be outdated"
   git status
   exit 1
 fi
 
 cargo test --all
+cargo test -p snips-nlu-ontology --all-features
+cargo bench -p snips-nlu-ontology --features msgpack --no-run
 
 if [[ "$KOTLIN_TESTS" == "true" ]]; then
   cd platforms/kotlin
   ./gradlew -Pdebug build --info
   cd ../..
 fi
+
+if [[ "$WASM_TESTS" == "true" ]]; then
+  rustup target add wasm32-unknown-unknown
+  cargo install wasm-pack
+  wasm-pack test --node platforms/wasm
+fi
@@ -0,0 +1,90 @@
+//! Benchmarks of the binary encoding against JSON, run with `cargo bench --features msgpack`
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use snips_nlu_ontology::binary::BinaryEncoding;
+use snips_nlu_ontology::*;
+
+fn intent_parser_result() -> IntentParserResult {
+    let slots = vec![
+        Slot {
+            raw_value: "tomorrow at 8pm".to_string(),
+            value: SlotValue::InstantTime(InstantTimeValue {
+                value: "2019-09-13 20:00:00 +02:00".to_string(),
+                grain: Grain::Hour,
+                precision: Precision::Exact,
+            }),
+            alternatives: vec![SlotValue::TimeInterval(TimeIntervalValue {
+                from: Some("2019-09-13 20:00:00 +02:00".to_string()),
+                to: Some("2019-09-13 21:00:00 +02:00".to_string()),
+            })],
+            range: 21..36,
+            entity: "snips/datetime".to_string(),
+            slot_name: "date".to_string(),
+            confidence_score: Some(0.92),
+        },
+        Slot {
+            raw_value: "Paris".to_string(),
+            value: SlotValue::City("Paris".into()),
+            alternatives: vec![],
+            range: 40..45,
+            entity: "snips/city".to_string(),
+            slot_name: "location".to_string(),
+            confidence_score: Some(0.87),
+        },
+    ];
+    IntentParserResult {
+        input: "what will the weather tomorrow at 8pm in Paris".to_string(),
+        intent: IntentClassifierResult {
+            intent_name: Some("searchWeatherForecast".to_string()),
+            confidence_score: 0.83,
+        },
+        slots: slots.clone(),
+        alternatives: vec![IntentParserAlternative {
+            intent: IntentClassifierResult {
+                intent_name: Some("searchWeatherNow".to_string()),
+                confidence_score: 0.12,
+            },
+            slots,
+        }],
+    }
+}
+
+fn encoding(c: &mut Criterion) {
+    let result = intent_parser_result();
+    let json = serde_json::to_vec(&result).unwrap();
+    let pretty_json = serde_json::to_vec_pretty(&result).unwrap();
+    let binary = result.to_binary().unwrap();
+    println!(
+        "Encoded sizes: pretty JSON {} bytes, JSON {} bytes, binary {} bytes",
+        pretty_json.len(),
+        json.len(),
+        binary.len()
+    );
+
+    let mut group = c.benchmark_group("serialize");
+    group.bench_function("json", |b| {
+        b.iter(|| serde_json::to_vec(black_box(&result)).unwrap())
+    });
+    group.bench_function("pretty_json", |b| {
+        b.iter(|| serde_json::to_vec_pretty(black_box(&result)).unwrap())
+    });
+    group.bench_function("binary", |b| {
+        b.iter(|| black_box(&result).to_binary().unwrap())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("deserialize");
+    group.bench_function("json", |b| {
+        b.iter(|| serde_json::from_slice::<IntentParserResult>(black_box(&json)).unwrap())
+    });
+    group.bench_function("pretty_json", |b| {
+        b.iter(|| serde_json::from_slice::<IntentParserResult>(black_box(&pretty_json)).unwrap())
+    });
+    group.bench_function("binary", |b| {
+        b.iter(|| IntentParserResult::from_binary(black_box(&binary)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, encoding);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+fn main() {
+    #[cfg(feature = "protobuf")]
+    generate_protobuf_messages();
+}
+
+/// Generates the Rust messages of `platforms/protobuf/ontology.proto`, using the `protoc`
+/// binary vendored by `protoc-bin-vendored` so that no local installation is needed
+#[cfg(feature = "protobuf")]
+fn generate_protobuf_messages() {
+    const PROTO_PATH: &str = "platforms/protobuf/ontology.proto";
+    println!("cargo:rerun-if-changed={}", PROTO_PATH);
+    let protoc = protoc_bin_vendored::protoc_bin_path().unwrap();
+    prost_build::Config::new()
+        .protoc_executable(protoc)
+        .compile_protos(&[PROTO_PATH], &["platforms/protobuf"])
+        .unwrap();
+}
@@ -1,7 +1,11 @@
 #[macro_use]
 extern crate prettytable;
 
-use prettytable::Table;
+mod definitions;
+mod kotlin;
+mod typescript;
+
+use prettytable::{Cell, Row, Table};
 use snips_nlu_ontology::*;
 use std::fs::File;
 use std::io::prelude::*;
@@ -11,6 +15,7 @@ fn main() {
     add_header(&mut readme);
     add_supported_languages(&mut readme);
     add_supported_builtin_entities(&mut readme);
+    add_builtin_entities_language_support(&mut readme);
     add_grammar_entity_documentation(&mut readme);
     add_gazetteer_entity_documentation(&mut readme);
     add_builtin_entities_examples(&mut readme);
@@ -18,6 +23,9 @@ fn main() {
 
     let mut file = File::create("../README.rst").unwrap();
     file.write_all(readme.as_bytes()).unwrap();
+
+    kotlin::generate_ontology_kt();
+    typescript::generate_ontology_d_ts();
 }
 
 fn add_header(readme: &mut String) {
@@ -73,6 +81,38 @@ fn add_supported_builtin_entities(readme: &mut String) {
     readme.push_str("\n");
 }
 
+fn add_builtin_entities_language_support(readme: &mut String) {
+    readme.push_str("Builtin entities language support\n");
+    readme.push_str("---------------------------------\n");
+    readme.push_str("\n");
+    let mut table = Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_DEFAULT);
+    let mut titles = vec![Cell::new("Entity")];
+    titles.extend(
+        Language::all()
+            .iter()
+            .map(|language| Cell::new(&*language.to_string())),
+    );
+    table.set_titles(Row::new(titles));
+
+    let mut all_entities = BuiltinEntityKind::all().iter().collect::<Vec<_>>();
+    all_entities.sort_by(|a, b| a.identifier().cmp(b.identifier()));
+
+    for entity in all_entities {
+        let mut cells = vec![Cell::new(&*entity.to_string())];
+        cells.extend(Language::all().iter().map(|language| {
+            if entity.supported_languages().contains(language) {
+                Cell::new("x")
+            } else {
+                Cell::new("")
+            }
+        }));
+        table.add_row(Row::new(cells));
+    }
+    readme.push_str(&*table.to_string());
+    readme.push_str("\n");
+}
+
 fn add_builtin_entities_examples(readme: &mut String) {
     let mut all_entities = BuiltinEntityKind::all().iter().collect::<Vec<_>>();
     all_entities.sort_by(|a, b| a.identifier().cmp(b.identifier()));
@@ -81,7 +121,10 @@ fn add_builtin_entities_examples(readme: &mut String) {
 
     readme.push_str("\n");
 
-    readme.push_str("The following sections provide results examples for each builtin entity.\n");
+    readme.push_str(
+        "The following sections provide input examples and results examples for each builtin \
+         entity.\n",
+    );
 
     readme.push_str("\n");
 
@@ -133,6 +176,21 @@ fn add_builtin_entity_results_examples(readme: &mut String, entity: BuiltinEntit
         .replace("--\n", "\n");
     readme.push_str(&*cleaned_title);
     readme.push_str("\n");
+
+    let mut examples_table = Table::new();
+    examples_table.set_format(*prettytable::format::consts::FORMAT_DEFAULT);
+    examples_table.set_titles(row!["Language", "Input examples"]);
+    for language in entity.supported_languages() {
+        let examples = entity
+            .examples(*language)
+            .iter()
+            .map(|example| format!("- {}", example))
+            .collect::<Vec<_>>()
+            .join("\n");
+        examples_table.add_row(row![language.full_name(), examples]);
+    }
+    readme.push_str(&*examples_table.to_string());
+    readme.push_str("\n");
     readme.push_str(".. code-block:: json\n");
     readme.push_str("\n   ");
     readme.push_str(&*entity.result_description().replace("\n", "\n   "));
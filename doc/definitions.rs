@@ -0,0 +1,15 @@
+//! Helpers to browse the definitions of the ontology JSON schema
+
+use schemars::schema::{Schema, SchemaObject};
+
+pub fn schema_object(schema: &Schema) -> &SchemaObject {
+    match schema {
+        Schema::Object(schema_object) => schema_object,
+        Schema::Bool(_) => panic!("Unsupported boolean schema"),
+    }
+}
+
+/// Removes the generic suffix of definition names, such as "_of_uint" in "Range_of_uint"
+pub fn base_name(definition_name: &str) -> String {
+    definition_name.split("_of_").next().unwrap().to_string()
+}
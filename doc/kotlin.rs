@@ -0,0 +1,327 @@
+//! Generation of the Kotlin ontology from the JSON schema of the Rust types
+
+use crate::definitions::{self, schema_object};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
+use snips_nlu_ontology::schema::ontology_json_schema;
+use std::fs::File;
+use std::io::prelude::*;
+
+const ONTOLOGY_KT_PATH: &str =
+    "../platforms/kotlin/src/main/kotlin/ai/snips/nlu/ontology/Ontology.kt";
+
+/// Definitions of the schema which are not part of the Kotlin ontology
+const EXCLUDED_DEFINITIONS: &[&str] = &["BuiltinEntity"];
+
+/// Fields which must be declared as a `MutableList` for Parceler
+const MUTABLE_LIST_FIELDS: &[(&str, &str)] = &[("Slot", "alternatives")];
+
+/// `SlotValue.Type` names which predate the generation and do not follow the naming convention
+const LEGACY_TYPE_NAMES: &[(&str, &str)] = &[
+    ("MusicAlbum", "MUSICALBUM"),
+    ("MusicArtist", "MUSICARTIST"),
+    ("MusicTrack", "MUSICTRACK"),
+];
+
+/// Variants of the sealed classes which are not part of the schema, as their class, type name,
+/// variant class and fields. The kind of unknown slot values is named `kindName`, as `kind` is the
+/// `Type` of the sealed class.
+const UNSERIALIZED_VARIANTS: &[(&str, &str, &str, &[(&str, &str)])] = &[(
+    "SlotValue",
+    "UNKNOWN",
+    "UnknownValue",
+    &[("kindName", "String"), ("payloadJson", "String")],
+)];
+
+const IMPORTS: &[&str] = &[
+    "com.fasterxml.jackson.annotation.JsonIgnore",
+    "com.fasterxml.jackson.annotation.JsonProperty",
+    "com.fasterxml.jackson.annotation.JsonSubTypes",
+    "com.fasterxml.jackson.annotation.JsonSubTypes.Type",
+    "com.fasterxml.jackson.annotation.JsonTypeInfo",
+    "org.parceler.Parcel",
+    "org.parceler.Parcel.Serialization.BEAN",
+    "org.parceler.ParcelConstructor",
+    "org.parceler.ParcelProperty",
+];
+
+pub fn generate_ontology_kt() {
+    let schema = ontology_json_schema();
+    let mut imports = IMPORTS.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let mut body = String::new();
+    for (name, definition) in schema.definitions.iter() {
+        if EXCLUDED_DEFINITIONS.contains(&&**name) {
+            continue;
+        }
+        let class_name = definitions::base_name(name);
+        let definition = schema_object(definition);
+        body.push('\n');
+        if let Some(variants) = definition
+            .subschemas
+            .as_ref()
+            .and_then(|s| s.one_of.as_ref())
+        {
+            add_sealed_class(&mut body, &mut imports, &class_name, variants);
+        } else if let Some(variants) = definition.enum_values.as_ref() {
+            let variants = variants.iter().map(|v| v.as_str().unwrap());
+            add_enum_class(&mut body, &class_name, variants);
+        } else {
+            add_data_class(&mut body, &class_name, definition);
+        }
+    }
+    imports.sort();
+
+    let mut ontology = String::new();
+    ontology.push_str(
+        "// Generated by doc/build.rs from the Rust ontology types, do not edit by hand\n",
+    );
+    ontology.push_str("package ai.snips.nlu.ontology\n");
+    ontology.push_str("\n");
+    for import in imports {
+        ontology.push_str(&*format!("import {}\n", import));
+    }
+    ontology.push_str(&*body);
+
+    let mut file = File::create(ONTOLOGY_KT_PATH).unwrap();
+    file.write_all(ontology.as_bytes()).unwrap();
+}
+
+fn add_enum_class<'a>(
+    kotlin: &mut String,
+    class_name: &str,
+    variants: impl Iterator<Item = &'a str>,
+) {
+    let variants = variants.map(upper_snake_case).collect::<Vec<_>>();
+    kotlin.push_str(&*format!(
+        "enum class {} {{ {} }}\n",
+        class_name,
+        variants.join(", ")
+    ));
+}
+
+fn add_data_class(kotlin: &mut String, class_name: &str, definition: &SchemaObject) {
+    kotlin.push_str("@Parcel(BEAN)\n");
+    kotlin.push_str(&*format!(
+        "data class {} @ParcelConstructor constructor(",
+        class_name
+    ));
+    let fields = fields(definition)
+        .map(|(name, kotlin_type)| {
+            if MUTABLE_LIST_FIELDS.contains(&(class_name, name)) {
+                let kotlin_type = kotlin_type.replacen("List", "MutableList", 1);
+                format!(
+                    "        //use a MutableList here to make parceler happy\n        {}",
+                    field(name, &kotlin_type)
+                )
+            } else {
+                format!("        {}", field(name, &kotlin_type))
+            }
+        })
+        .collect::<Vec<_>>();
+    kotlin.push_str(&*format!("\n{})\n", fields.join(",\n")));
+}
+
+fn add_sealed_class(
+    kotlin: &mut String,
+    imports: &mut Vec<String>,
+    class_name: &str,
+    variants: &[Schema],
+) {
+    let variants = variants
+        .iter()
+        .map(|variant| {
+            let variant = schema_object(variant);
+            let kind_schema = schema_object(&variant.object.as_ref().unwrap().properties["kind"]);
+            let kind = kind_schema.enum_values.as_ref().unwrap()[0]
+                .as_str()
+                .unwrap();
+            (kind, type_name(kind), format!("{}Value", kind), variant)
+        })
+        .collect::<Vec<_>>();
+
+    for (_, type_name, variant_class, _) in variants.iter() {
+        imports.push(format!(
+            "ai.snips.nlu.ontology.{}.{}",
+            class_name, variant_class
+        ));
+        imports.push(format!(
+            "ai.snips.nlu.ontology.{}.Type.{}",
+            class_name, type_name
+        ));
+    }
+
+    kotlin.push_str(
+        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.EXISTING_PROPERTY, \
+         property = \"kind\")\n",
+    );
+    let sub_types = variants
+        .iter()
+        .map(|(kind, _, variant_class, _)| {
+            format!(
+                "        Type(value = {}::class, name = \"{}\")",
+                variant_class, kind
+            )
+        })
+        .collect::<Vec<_>>();
+    kotlin.push_str(&*format!("@JsonSubTypes(\n{}\n)\n", sub_types.join(",\n")));
+    kotlin.push_str(&*format!(
+        "sealed class {}(val kind: Type) {{\n",
+        class_name
+    ));
+    kotlin.push_str("\n");
+    kotlin.push_str("    @JsonIgnore\n");
+    kotlin.push_str("    @Deprecated(\"use kind instead\")\n");
+    kotlin.push_str("    val type = kind\n");
+    kotlin.push_str("\n");
+
+    let unserialized_variants = UNSERIALIZED_VARIANTS
+        .iter()
+        .filter(|(variant_of, _, _, _)| *variant_of == class_name)
+        .collect::<Vec<_>>();
+    let type_names = variants
+        .iter()
+        .map(|(kind, type_name, _, _)| format!("        @JsonProperty(\"{}\") {}", kind, type_name))
+        .chain(
+            unserialized_variants
+                .iter()
+                .map(|(_, type_name, _, _)| format!("        {}", type_name)),
+        )
+        .collect::<Vec<_>>();
+    kotlin.push_str("    @Parcel\n");
+    kotlin.push_str(&*format!(
+        "    enum class Type {{\n{}\n    }}\n",
+        type_names.join(",\n")
+    ));
+
+    for (_, type_name, variant_class, variant) in variants.iter() {
+        let fields = fields(variant)
+            .filter(|(name, _)| *name != "kind")
+            .map(|(name, kotlin_type)| field(name, &kotlin_type))
+            .collect::<Vec<_>>();
+        add_variant_class(kotlin, class_name, type_name, variant_class, &fields);
+    }
+    for (_, type_name, variant_class, fields) in unserialized_variants {
+        imports.push(format!(
+            "ai.snips.nlu.ontology.{}.Type.{}",
+            class_name, type_name
+        ));
+        let fields = fields
+            .iter()
+            .map(|(name, kotlin_type)| field(name, kotlin_type))
+            .collect::<Vec<_>>();
+        add_variant_class(kotlin, class_name, type_name, variant_class, &fields);
+    }
+    kotlin.push_str("}\n");
+}
+
+fn add_variant_class(
+    kotlin: &mut String,
+    class_name: &str,
+    type_name: &str,
+    variant_class: &str,
+    fields: &[String],
+) {
+    kotlin.push_str("\n");
+    kotlin.push_str("    @Parcel(BEAN)\n");
+    if fields.len() == 1 {
+        kotlin.push_str(&*format!(
+            "    data class {} @ParcelConstructor constructor({}) : {}({})\n",
+            variant_class, fields[0], class_name, type_name
+        ));
+    } else {
+        let fields = fields
+            .iter()
+            .map(|field| format!("            {}", field))
+            .collect::<Vec<_>>();
+        kotlin.push_str(&*format!(
+            "    data class {} @ParcelConstructor constructor(\n{}) : {}({})\n",
+            variant_class,
+            fields.join(",\n"),
+            class_name,
+            type_name
+        ));
+    }
+}
+
+/// Returns the names and Kotlin types of the properties, in declaration order
+fn fields(definition: &SchemaObject) -> impl Iterator<Item = (&str, String)> {
+    let properties = &definition.object.as_ref().unwrap().properties;
+    properties
+        .iter()
+        .map(|(name, schema)| (&**name, kotlin_type(schema_object(schema))))
+}
+
+fn field(name: &str, kotlin_type: &str) -> String {
+    format!(
+        "@ParcelProperty(\"{}\") val {}: {}",
+        name, name, kotlin_type
+    )
+}
+
+fn kotlin_type(schema: &SchemaObject) -> String {
+    if let Some(reference) = schema.reference.as_ref() {
+        let name = reference.trim_start_matches("#/definitions/");
+        return definitions::base_name(name);
+    }
+    if let Some(any_of) = schema.subschemas.as_ref().and_then(|s| s.any_of.as_ref()) {
+        let non_null_schema = any_of
+            .iter()
+            .map(schema_object)
+            .find(|s| s.instance_type != Some(InstanceType::Null.into()))
+            .unwrap();
+        return format!("{}?", kotlin_type(non_null_schema));
+    }
+    let (instance_type, nullable) = match schema.instance_type.as_ref().unwrap() {
+        SingleOrVec::Single(instance_type) => (**instance_type, false),
+        SingleOrVec::Vec(instance_types) => (
+            *instance_types
+                .iter()
+                .find(|t| **t != InstanceType::Null)
+                .unwrap(),
+            instance_types.contains(&InstanceType::Null),
+        ),
+    };
+    let kotlin_type = match (instance_type, schema.format.as_ref().map(|f| &**f)) {
+        (InstanceType::String, _) => "String".to_string(),
+        (InstanceType::Boolean, _) => "Boolean".to_string(),
+        (InstanceType::Number, Some("float")) => "Float".to_string(),
+        (InstanceType::Number, _) => "Double".to_string(),
+        (InstanceType::Integer, Some("int64")) | (InstanceType::Integer, Some("uint64")) => {
+            "Long".to_string()
+        }
+        (InstanceType::Integer, _) => "Int".to_string(),
+        (InstanceType::Array, _) => {
+            let items = schema.array.as_ref().and_then(|a| a.items.as_ref());
+            match items {
+                Some(SingleOrVec::Single(items)) => {
+                    format!("List<{}>", kotlin_type(schema_object(items)))
+                }
+                _ => panic!("Unsupported array schema"),
+            }
+        }
+        (instance_type, _) => panic!("Unsupported schema type: {:?}", instance_type),
+    };
+    if nullable {
+        format!("{}?", kotlin_type)
+    } else {
+        kotlin_type
+    }
+}
+
+fn type_name(kind: &str) -> String {
+    LEGACY_TYPE_NAMES
+        .iter()
+        .find(|(legacy_kind, _)| *legacy_kind == kind)
+        .map(|(_, legacy_name)| legacy_name.to_string())
+        .unwrap_or_else(|| upper_snake_case(kind))
+}
+
+fn upper_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            result.push('_');
+        }
+        result.extend(c.to_uppercase());
+    }
+    result
+}
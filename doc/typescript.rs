@@ -0,0 +1,182 @@
+//! Generation of the TypeScript type definitions of the ontology
+//!
+//! Languages and entity kinds are listed from their `all()` functions, while the other types are
+//! generated from the JSON schema of the Rust types.
+
+use crate::definitions::{self, schema_object};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
+use snips_nlu_ontology::schema::ontology_json_schema;
+use snips_nlu_ontology::*;
+use std::fs::File;
+use std::io::prelude::*;
+
+const ONTOLOGY_D_TS_PATH: &str = "../platforms/typescript/ontology.d.ts";
+
+/// Definitions of the schema which are not part of the TypeScript ontology
+const EXCLUDED_DEFINITIONS: &[&str] = &["BuiltinEntity"];
+
+/// Fields which are not serialized when they are empty, the other fields being always present
+/// even when they are nullable or have a default value
+const SKIPPED_WHEN_EMPTY_FIELDS: &[(&str, &str)] = &[("Slot", "confidenceScore")];
+
+pub fn generate_ontology_d_ts() {
+    let mut typescript = String::new();
+    typescript.push_str(
+        "// Generated by doc/build.rs from the Rust ontology types, do not edit by hand\n",
+    );
+
+    let languages = Language::all().iter().map(|l| l.to_string());
+    add_string_union(&mut typescript, "Language", languages);
+    add_entity_kind_union(
+        &mut typescript,
+        "BuiltinEntityKind",
+        BuiltinEntityKind::all(),
+    );
+    add_entity_kind_union(
+        &mut typescript,
+        "GrammarEntityKind",
+        GrammarEntityKind::all(),
+    );
+    add_entity_kind_union(
+        &mut typescript,
+        "BuiltinGazetteerEntityKind",
+        BuiltinGazetteerEntityKind::all(),
+    );
+
+    let schema = ontology_json_schema();
+    for (name, definition) in schema.definitions.iter() {
+        if EXCLUDED_DEFINITIONS.contains(&&**name) {
+            continue;
+        }
+        let type_name = definitions::base_name(name);
+        let definition = schema_object(definition);
+        if let Some(variants) = definition
+            .subschemas
+            .as_ref()
+            .and_then(|s| s.one_of.as_ref())
+        {
+            add_discriminated_union(&mut typescript, &type_name, variants);
+        } else if let Some(variants) = definition.enum_values.as_ref() {
+            let variants = variants.iter().map(|v| v.as_str().unwrap().to_string());
+            add_string_union(&mut typescript, &type_name, variants);
+        } else {
+            add_interface(&mut typescript, &type_name, definition);
+        }
+    }
+
+    let mut file = File::create(ONTOLOGY_D_TS_PATH).unwrap();
+    file.write_all(typescript.as_bytes()).unwrap();
+}
+
+fn add_entity_kind_union<T: IntoBuiltinEntityKind>(
+    typescript: &mut String,
+    type_name: &str,
+    kinds: &[T],
+) {
+    let identifiers = kinds.iter().map(|kind| kind.identifier().to_string());
+    add_string_union(typescript, type_name, identifiers);
+}
+
+fn add_string_union(
+    typescript: &mut String,
+    type_name: &str,
+    values: impl Iterator<Item = String>,
+) {
+    let literals = values
+        .map(|value| format!("    | \"{}\"", value))
+        .collect::<Vec<_>>();
+    typescript.push_str("\n");
+    typescript.push_str(&*format!(
+        "export type {} =\n{};\n",
+        type_name,
+        literals.join("\n")
+    ));
+}
+
+fn add_discriminated_union(typescript: &mut String, type_name: &str, variants: &[Schema]) {
+    let variants = variants
+        .iter()
+        .map(|variant| {
+            let variant = schema_object(variant);
+            let kind_schema = schema_object(&variant.object.as_ref().unwrap().properties["kind"]);
+            let kind = kind_schema.enum_values.as_ref().unwrap()[0]
+                .as_str()
+                .unwrap();
+            (format!("{}Value", kind), variant)
+        })
+        .collect::<Vec<_>>();
+    let variant_names = variants
+        .iter()
+        .map(|(variant_name, _)| format!("    | {}", variant_name))
+        .collect::<Vec<_>>();
+    typescript.push_str("\n");
+    typescript.push_str(&*format!(
+        "export type {} =\n{};\n",
+        type_name,
+        variant_names.join("\n")
+    ));
+    for (variant_name, variant) in variants.iter() {
+        add_interface(typescript, variant_name, variant);
+    }
+}
+
+fn add_interface(typescript: &mut String, type_name: &str, definition: &SchemaObject) {
+    let object = definition.object.as_ref().unwrap();
+    typescript.push_str("\n");
+    typescript.push_str(&*format!("export interface {} {{\n", type_name));
+    for (name, schema) in object.properties.iter() {
+        let optional = if SKIPPED_WHEN_EMPTY_FIELDS.contains(&(type_name, name)) {
+            "?"
+        } else {
+            ""
+        };
+        typescript.push_str(&*format!(
+            "    {}{}: {};\n",
+            name,
+            optional,
+            typescript_type(schema_object(schema))
+        ));
+    }
+    typescript.push_str("}\n");
+}
+
+fn typescript_type(schema: &SchemaObject) -> String {
+    if let Some(reference) = schema.reference.as_ref() {
+        return definitions::base_name(reference.trim_start_matches("#/definitions/"));
+    }
+    if let Some(any_of) = schema.subschemas.as_ref().and_then(|s| s.any_of.as_ref()) {
+        let types = any_of
+            .iter()
+            .map(|s| typescript_type(schema_object(s)))
+            .collect::<Vec<_>>();
+        return types.join(" | ");
+    }
+    if let Some(values) = schema.enum_values.as_ref() {
+        let literals = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        return literals.join(" | ");
+    }
+    let types = match schema.instance_type.as_ref().unwrap() {
+        SingleOrVec::Single(instance_type) => vec![**instance_type],
+        SingleOrVec::Vec(instance_types) => instance_types.clone(),
+    };
+    let types = types
+        .into_iter()
+        .map(|instance_type| match instance_type {
+            InstanceType::String => "string".to_string(),
+            InstanceType::Number | InstanceType::Integer => "number".to_string(),
+            InstanceType::Boolean => "boolean".to_string(),
+            InstanceType::Null => "null".to_string(),
+            InstanceType::Array => {
+                let items = schema.array.as_ref().and_then(|a| a.items.as_ref());
+                match items {
+                    Some(SingleOrVec::Single(items)) => {
+                        format!("{}[]", typescript_type(schema_object(items)))
+                    }
+                    _ => panic!("Unsupported array schema"),
+                }
+            }
+            InstanceType::Object => panic!("Unsupported inline object schema"),
+        })
+        .collect::<Vec<_>>();
+    types.join(" | ")
+}
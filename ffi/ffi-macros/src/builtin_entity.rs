@@ -2,17 +2,18 @@
 
 use crate::errors::*;
 use crate::ontology::*;
-use ffi_utils::take_back_c_string;
-use ffi_utils::{point_to_string, CStringArray, RawPointerConverter};
+use ffi_utils::{create_rust_string_from, take_back_c_string};
+use ffi_utils::{AsRust, CStringArray, RawPointerConverter};
 use lazy_static::lazy_static;
 use libc;
 use snips_nlu_ontology::{
     BuiltinEntity, BuiltinEntityKind, BuiltinGazetteerEntityKind, GrammarEntityKind,
-    IntoBuiltinEntityKind,
+    IntoBuiltinEntityKind, Language, LenientBuiltinEntity, LenientEntityKind,
 };
-use std::convert::From;
-use std::ffi::{CStr, CString};
+use std::convert::{From, TryFrom};
+use std::ffi::CString;
 use std::slice;
+use std::str::FromStr;
 
 #[repr(C)]
 #[derive(Debug)]
@@ -22,17 +23,59 @@ pub struct CBuiltinEntity {
     pub value: *const libc::c_char,
     pub range_start: i32,
     pub range_end: i32,
+    /// Alternative values of the entity, which may be null when there is none
+    pub alternatives: *const CSlotValueArray,
 }
 
-impl From<BuiltinEntity> for CBuiltinEntity {
-    fn from(e: BuiltinEntity) -> Self {
-        Self {
+impl TryFrom<LenientBuiltinEntity> for CBuiltinEntity {
+    type Error = failure::Error;
+
+    /// Fails when the range does not fit in an `i32`
+    fn try_from(e: LenientBuiltinEntity) -> Result<Self> {
+        let (range_start, range_end) = i32_range(&e.range)?;
+        let entity_kind = CString::new(e.entity_kind.identifier())?;
+        let value = CString::new(e.value)?;
+        Ok(Self {
             entity: CSlotValue::from(e.entity),
-            entity_kind: CString::new(e.entity_kind.identifier()).unwrap().into_raw(),
-            value: CString::new(e.value).unwrap().into_raw(),
-            range_start: e.range.start as i32,
-            range_end: e.range.end as i32,
-        }
+            entity_kind: entity_kind.into_raw(),
+            value: value.into_raw(),
+            range_start,
+            range_end,
+            alternatives: CSlotValueArray::from(e.alternatives).into_raw_pointer(),
+        })
+    }
+}
+
+impl TryFrom<BuiltinEntity> for CBuiltinEntity {
+    type Error = failure::Error;
+
+    fn try_from(e: BuiltinEntity) -> Result<Self> {
+        Self::try_from(LenientBuiltinEntity::from(e))
+    }
+}
+
+impl AsRust<LenientBuiltinEntity> for CBuiltinEntity {
+    fn as_rust(&self) -> Result<LenientBuiltinEntity> {
+        let entity_kind = create_rust_string_from!(self.entity_kind);
+        Ok(LenientBuiltinEntity {
+            value: create_rust_string_from!(self.value),
+            range: usize_range(self.range_start, self.range_end)?,
+            entity: self.entity.as_rust()?,
+            alternatives: if self.alternatives.is_null() {
+                vec![]
+            } else {
+                unsafe { &*self.alternatives }.as_rust()?
+            },
+            entity_kind: LenientEntityKind::from_identifier(&entity_kind),
+        })
+    }
+}
+
+/// Fails when the kind of the entity is unknown to this version of the ontology
+impl AsRust<BuiltinEntity> for CBuiltinEntity {
+    fn as_rust(&self) -> Result<BuiltinEntity> {
+        let entity: LenientBuiltinEntity = self.as_rust()?;
+        BuiltinEntity::try_from(entity)
     }
 }
 
@@ -40,6 +83,9 @@ impl Drop for CBuiltinEntity {
     fn drop(&mut self) {
         take_back_c_string!(self.value);
         take_back_c_string!(self.entity_kind);
+        if !self.alternatives.is_null() {
+            let _ = unsafe { CSlotValueArray::drop_raw_pointer(self.alternatives) };
+        }
     }
 }
 
@@ -141,7 +187,205 @@ pub fn get_builtin_entity_shortname(
     entity_name: *const libc::c_char,
     result: *mut *const libc::c_char,
 ) -> Result<()> {
-    let entity_str = unsafe { CStr::from_ptr(entity_name) }.to_str()?;
+    let entity_str = str_from_non_null(entity_name, "entity_name")?;
     let entity_kind = BuiltinEntityKind::from_identifier(entity_str)?;
-    point_to_string(result, entity_kind.to_string())
+    point_to_c_string(result, entity_kind.to_string(), "result")
+}
+
+pub fn get_supported_builtin_entities(
+    language: *const libc::c_char,
+    results: *mut *const CStringArray,
+) -> Result<()> {
+    let language = parse_language(language)?;
+    let entities = language
+        .supported_builtin_entities()
+        .iter()
+        .map(|kind| kind.identifier().to_string())
+        .collect::<Vec<_>>();
+    point_to_string_array(results, entities)
+}
+
+pub fn get_supported_grammar_entities(
+    language: *const libc::c_char,
+    results: *mut *const CStringArray,
+) -> Result<()> {
+    let language = parse_language(language)?;
+    let entities = language
+        .supported_grammar_entities()
+        .iter()
+        .map(|kind| kind.identifier().to_string())
+        .collect::<Vec<_>>();
+    point_to_string_array(results, entities)
+}
+
+pub fn get_supported_builtin_gazetteer_entities(
+    language: *const libc::c_char,
+    results: *mut *const CStringArray,
+) -> Result<()> {
+    let language = parse_language(language)?;
+    let entities = language
+        .supported_gazetteer_entities()
+        .iter()
+        .map(|kind| kind.identifier().to_string())
+        .collect::<Vec<_>>();
+    point_to_string_array(results, entities)
+}
+
+pub fn get_builtin_entity_examples(
+    builtin_entity_kind: *const libc::c_char,
+    language: *const libc::c_char,
+    results: *mut *const CStringArray,
+) -> Result<()> {
+    let entity_kind_str = str_from_non_null(builtin_entity_kind, "builtin_entity_kind")?;
+    let entity_kind = BuiltinEntityKind::from_identifier(entity_kind_str)?;
+    let language = parse_language(language)?;
+    let examples = entity_kind
+        .examples(language)
+        .iter()
+        .map(|example| example.to_string())
+        .collect::<Vec<_>>();
+    point_to_string_array(results, examples)
+}
+
+fn parse_language(language: *const libc::c_char) -> Result<Language> {
+    let language_str = str_from_non_null(language, "language")?;
+    Ok(Language::from_str(language_str)?)
+}
+
+fn point_to_string_array(results: *mut *const CStringArray, values: Vec<String>) -> Result<()> {
+    let results = non_null_output(results, "results")?;
+    *results = CStringArray::from(values).into_raw_pointer();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::tests::round_trip_test;
+    use snips_nlu_ontology::{NumberValue, SlotValue};
+
+    #[test]
+    fn round_trip_c_builtin_entity() {
+        round_trip_test::<_, CBuiltinEntity>(BuiltinEntity {
+            value: "twenty two".to_string(),
+            range: 3..13,
+            entity: SlotValue::Number(NumberValue { value: 22.0 }),
+            alternatives: vec![SlotValue::Number(NumberValue { value: 20.0 })],
+            entity_kind: BuiltinEntityKind::Number,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_builtin_entity_without_alternatives() {
+        round_trip_test::<_, CBuiltinEntity>(BuiltinEntity {
+            value: "paris".to_string(),
+            range: 0..5,
+            entity: SlotValue::City("Paris".into()),
+            alternatives: vec![],
+            entity_kind: BuiltinEntityKind::City,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_builtin_entity_of_unknown_kind() {
+        // Given
+        let json = r#"{
+            "value": "a fortnight",
+            "range": {"start": 0, "end": 11},
+            "entity": {"kind": "Fortnight", "count": 1},
+            "alternatives": [],
+            "entity_kind": "snips/fortnight"
+        }"#;
+        let entity: LenientBuiltinEntity = snips_nlu_ontology::lenient::from_str(json).unwrap();
+        let c_entity = CBuiltinEntity::try_from(entity.clone()).unwrap();
+
+        // When
+        let strict_entity: Result<BuiltinEntity> = c_entity.as_rust();
+
+        // Then
+        round_trip_test::<_, CBuiltinEntity>(entity);
+        assert_eq!(
+            "Unknown entity identifier: snips/fortnight",
+            strict_entity.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_range_above_i32_max_is_rejected() {
+        // Given
+        let entity = BuiltinEntity {
+            value: "twenty two".to_string(),
+            range: 3..i32::MAX as usize + 1,
+            entity: SlotValue::Number(NumberValue { value: 22.0 }),
+            alternatives: vec![],
+            entity_kind: BuiltinEntityKind::Number,
+        };
+
+        // When
+        let c_entity = CBuiltinEntity::try_from(entity);
+
+        // Then
+        assert_eq!(
+            "Range too large for the FFI: 3..2147483648",
+            c_entity.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_null_alternatives_are_empty() {
+        // Given
+        let entity = BuiltinEntity {
+            value: "twenty two".to_string(),
+            range: 3..13,
+            entity: SlotValue::Number(NumberValue { value: 22.0 }),
+            alternatives: vec![],
+            entity_kind: BuiltinEntityKind::Number,
+        };
+        let mut c_entity = CBuiltinEntity::try_from(entity.clone()).unwrap();
+        let _ = unsafe { CSlotValueArray::drop_raw_pointer(c_entity.alternatives) };
+        c_entity.alternatives = std::ptr::null();
+
+        // When
+        let converted_entity: BuiltinEntity = c_entity.as_rust().unwrap();
+
+        // Then
+        assert_eq!(entity, converted_entity);
+    }
+
+    #[test]
+    fn test_null_language_is_rejected() {
+        // Given
+        let mut results: *const CStringArray = std::ptr::null();
+
+        // When
+        let result = get_supported_builtin_entities(std::ptr::null(), &mut results);
+
+        // Then
+        assert_eq!(
+            "Unexpected null pointer: language",
+            result.unwrap_err().to_string()
+        );
+        assert!(results.is_null());
+    }
+
+    #[test]
+    fn test_null_output_pointers_are_rejected() {
+        // Given
+        let entity_name = CString::new("snips/number").unwrap();
+        let language = CString::new("en").unwrap();
+
+        // When
+        let shortname = get_builtin_entity_shortname(entity_name.as_ptr(), std::ptr::null_mut());
+        let entities = get_supported_builtin_entities(language.as_ptr(), std::ptr::null_mut());
+
+        // Then
+        assert_eq!(
+            "Unexpected null pointer: result",
+            shortname.unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Unexpected null pointer: results",
+            entities.unwrap_err().to_string()
+        );
+    }
 }
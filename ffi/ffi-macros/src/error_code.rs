@@ -0,0 +1,142 @@
+#![allow(non_camel_case_types)]
+
+use failure::{bail, Fallible};
+use snips_nlu_ontology::errors::OntologyError;
+use std::cell::Cell;
+
+/// Enum describing the kind of the last error which occurred in the current thread
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SNIPS_ONTOLOGY_ERROR_CODE {
+    /// No error occurred
+    SNIPS_ONTOLOGY_ERROR_CODE_NONE = 0,
+    /// An error which is not specific to the ontology occurred
+    SNIPS_ONTOLOGY_ERROR_CODE_OTHER = 1,
+    /// The language code does not match any supported language
+    SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_LANGUAGE = 2,
+    /// The identifier does not match any builtin entity
+    SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_ENTITY_IDENTIFIER = 3,
+    /// The name does not match any entity kind
+    SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_ENTITY_KIND = 4,
+    /// The builtin entity is not a grammar entity
+    SNIPS_ONTOLOGY_ERROR_CODE_NOT_A_GRAMMAR_ENTITY = 5,
+    /// The builtin entity is not a gazetteer entity
+    SNIPS_ONTOLOGY_ERROR_CODE_NOT_A_GAZETTEER_ENTITY = 6,
+    /// The range is not a valid range of the input in the given unit
+    SNIPS_ONTOLOGY_ERROR_CODE_INVALID_RANGE = 7,
+}
+
+impl<'a> From<&'a OntologyError> for SNIPS_ONTOLOGY_ERROR_CODE {
+    fn from(error: &'a OntologyError) -> Self {
+        match error {
+            OntologyError::UnknownLanguage(_) => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_LANGUAGE
+            }
+            OntologyError::UnknownEntityIdentifier(_) => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_ENTITY_IDENTIFIER
+            }
+            OntologyError::UnknownEntityKind { .. } => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_ENTITY_KIND
+            }
+            OntologyError::NotAGrammarEntity(_) => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NOT_A_GRAMMAR_ENTITY
+            }
+            OntologyError::NotAGazetteerEntity(_) => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NOT_A_GAZETTEER_ENTITY
+            }
+            OntologyError::InvalidRange { .. } => {
+                SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_INVALID_RANGE
+            }
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR_CODE: Cell<SNIPS_ONTOLOGY_ERROR_CODE> =
+        const { Cell::new(SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NONE) };
+}
+
+/// Records the code of the error contained in `result` so that it can be retrieved with
+/// `get_last_error_code`, the code being reset to `SNIPS_ONTOLOGY_ERROR_CODE_NONE` on success
+pub fn with_error_code<T>(result: Fallible<T>) -> Fallible<T> {
+    let code = match result {
+        Ok(_) => SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NONE,
+        Err(ref error) => error
+            .iter_chain()
+            .filter_map(|cause| cause.downcast_ref::<OntologyError>())
+            .next()
+            .map(SNIPS_ONTOLOGY_ERROR_CODE::from)
+            .unwrap_or(SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_OTHER),
+    };
+    LAST_ERROR_CODE.with(|last| last.set(code));
+    result
+}
+
+pub fn get_last_error_code(error_code: *mut SNIPS_ONTOLOGY_ERROR_CODE) -> Fallible<()> {
+    let code = LAST_ERROR_CODE.with(|last| last.get());
+    write_error_code(error_code, code)
+}
+
+fn write_error_code(
+    pointer: *mut SNIPS_ONTOLOGY_ERROR_CODE,
+    code: SNIPS_ONTOLOGY_ERROR_CODE,
+) -> Fallible<()> {
+    if pointer.is_null() {
+        bail!("Unexpected null pointer: error_code")
+    }
+    unsafe { *pointer = code };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snips_nlu_ontology::{BuiltinEntityKind, GrammarEntityKind, Language};
+    use std::str::FromStr;
+
+    fn last_error_code() -> SNIPS_ONTOLOGY_ERROR_CODE {
+        let mut code = SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NONE;
+        get_last_error_code(&mut code).unwrap();
+        code
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let _ = with_error_code(Language::from_str("xx").map_err(Into::into));
+        assert_eq!(
+            SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_LANGUAGE,
+            last_error_code()
+        );
+
+        let _ =
+            with_error_code(GrammarEntityKind::from_identifier("snips/city").map_err(Into::into));
+        assert_eq!(
+            SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NOT_A_GRAMMAR_ENTITY,
+            last_error_code()
+        );
+
+        let _ =
+            with_error_code(BuiltinEntityKind::from_identifier("snips/foo").map_err(Into::into));
+        assert_eq!(
+            SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_UNKNOWN_ENTITY_IDENTIFIER,
+            last_error_code()
+        );
+
+        let _ = with_error_code::<()>(Err(failure::format_err!("foo")));
+        assert_eq!(
+            SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_OTHER,
+            last_error_code()
+        );
+
+        let _ = with_error_code(Ok(()));
+        assert_eq!(
+            SNIPS_ONTOLOGY_ERROR_CODE::SNIPS_ONTOLOGY_ERROR_CODE_NONE,
+            last_error_code()
+        );
+    }
+
+    #[test]
+    fn test_null_error_code_is_rejected() {
+        assert!(get_last_error_code(std::ptr::null_mut()).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 mod builtin_entity;
+mod error_code;
 mod language;
 mod ontology;
 pub use builtin_entity::*;
+pub use error_code::*;
 pub use language::*;
 pub use ontology::*;
 use snips_nlu_ontology::*;
@@ -9,6 +11,13 @@ use snips_nlu_ontology::*;
 #[macro_export]
 macro_rules! export_nlu_ontology_c_symbols {
     () => {
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_get_last_error_code(
+            error_code: *mut $crate::SNIPS_ONTOLOGY_ERROR_CODE,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::get_last_error_code(error_code))
+        }
+
         #[no_mangle]
         pub extern "C" fn snips_nlu_ontology_destroy_string_array(
             ptr: *mut ::ffi_utils::CStringArray,
@@ -35,7 +44,9 @@ macro_rules! export_nlu_ontology_c_symbols {
             entity_name: *const libc::c_char,
             result: *mut *const libc::c_char,
         ) -> ::ffi_utils::SNIPS_RESULT {
-            wrap!($crate::get_builtin_entity_shortname(entity_name, result))
+            wrap!($crate::with_error_code(
+                $crate::get_builtin_entity_shortname(entity_name, result)
+            ))
         }
 
         #[no_mangle]
@@ -52,5 +63,194 @@ macro_rules! export_nlu_ontology_c_symbols {
         pub extern "C" fn snips_nlu_ontology_all_gazetteer_entities() -> ::ffi_utils::CStringArray {
             $crate::all_gazetteer_entities()
         }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_supported_builtin_entities(
+            language: *const libc::c_char,
+            results: *mut *const ::ffi_utils::CStringArray,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::get_supported_builtin_entities(language, results)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_supported_grammar_entities(
+            language: *const libc::c_char,
+            results: *mut *const ::ffi_utils::CStringArray,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::get_supported_grammar_entities(language, results)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_supported_builtin_gazetteer_entities(
+            language: *const libc::c_char,
+            results: *mut *const ::ffi_utils::CStringArray,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::get_supported_builtin_gazetteer_entities(language, results)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_builtin_entity_examples(
+            builtin_entity_kind: *const libc::c_char,
+            language: *const libc::c_char,
+            results: *mut *const ::ffi_utils::CStringArray,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::get_builtin_entity_examples(builtin_entity_kind, language, results)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_convert_temperature_value(
+            temperature: *const $crate::CTemperatureValue,
+            unit: $crate::SNIPS_TEMPERATURE_UNIT,
+            result: *mut libc::c_float,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::convert_temperature_value(
+                temperature,
+                unit,
+                result
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_create_intent_parser_result(
+            input: *const libc::c_char,
+            intent: *const $crate::CIntentClassifierResult,
+            slots: *const $crate::CSlotList,
+            alternatives: *const $crate::CIntentParserAlternativeArray,
+            result: *mut *const $crate::CIntentParserResult,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::create_intent_parser_result(input, intent, slots, alternatives, result)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_clone_intent_parser_result(
+            intent_parser_result: *const $crate::CIntentParserResult,
+            result: *mut *const $crate::CIntentParserResult,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::clone_intent_parser_result(
+                intent_parser_result,
+                result
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_convert_intent_parser_result_ranges(
+            intent_parser_result: *const $crate::CIntentParserResult,
+            from: $crate::SNIPS_RANGE_UNIT,
+            to: $crate::SNIPS_RANGE_UNIT,
+            result: *mut *const $crate::CIntentParserResult,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::convert_intent_parser_result_ranges(intent_parser_result, from, to, result)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_destroy_intent_parser_result(
+            intent_parser_result: *mut $crate::CIntentParserResult,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::destroy_intent_parser_result(intent_parser_result)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_create_slot(
+            raw_value: *const libc::c_char,
+            value: *const $crate::CSlotValue,
+            alternatives: *const $crate::CSlotValueArray,
+            entity: *const libc::c_char,
+            slot_name: *const libc::c_char,
+            range_start: i32,
+            range_end: i32,
+            confidence_score: libc::c_float,
+            result: *mut *const $crate::CSlot,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::create_slot(
+                raw_value,
+                value,
+                alternatives,
+                entity,
+                slot_name,
+                range_start,
+                range_end,
+                confidence_score,
+                result
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_clone_slot(
+            slot: *const $crate::CSlot,
+            result: *mut *const $crate::CSlot,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::clone_slot(slot, result)))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_destroy_slot(
+            slot: *mut $crate::CSlot,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::destroy_slot(slot)))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_create_slot_value(
+            value_type: $crate::SNIPS_SLOT_VALUE_TYPE,
+            value: *const libc::c_void,
+            result: *mut *const $crate::CSlotValue,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::create_slot_value(
+                value_type, value, result
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_clone_slot_value(
+            slot_value: *const $crate::CSlotValue,
+            result: *mut *const $crate::CSlotValue,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::clone_slot_value(
+                slot_value, result
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_destroy_slot_value(
+            slot_value: *mut $crate::CSlotValue,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code($crate::destroy_slot_value(
+                slot_value
+            )))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_intent_parser_result_from_json(
+            json: *const libc::c_char,
+            result: *mut *const $crate::CIntentParserResult,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::intent_parser_result_from_json(json, result)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_intent_parser_result_to_json(
+            intent_parser_result: *const $crate::CIntentParserResult,
+            json: *mut *const libc::c_char,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::with_error_code(
+                $crate::intent_parser_result_to_json(intent_parser_result, json)
+            ))
+        }
     };
 }
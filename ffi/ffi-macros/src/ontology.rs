@@ -1,13 +1,16 @@
 #![allow(non_camel_case_types)]
 
-use failure::{bail, Fallible, ResultExt};
+use failure::{bail, format_err, Fallible, ResultExt};
 use ffi_utils::{
     create_optional_rust_string_from, create_rust_string_from, take_back_c_string,
     take_back_nullable_c_string, AsRust, RawPointerConverter,
 };
 use libc;
 use snips_nlu_ontology::*;
-use std::ffi::CString;
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::mem::ManuallyDrop;
+use std::ops::Range;
 use std::ptr::null;
 use std::slice;
 
@@ -25,15 +28,20 @@ pub struct CIntentParserResult {
     pub alternatives: *const CIntentParserAlternativeArray,
 }
 
-impl From<IntentParserResult> for CIntentParserResult {
-    fn from(input: IntentParserResult) -> Self {
-        Self {
-            input: CString::new(input.input).unwrap().into_raw(),
+impl TryFrom<IntentParserResult> for CIntentParserResult {
+    type Error = failure::Error;
+
+    /// Fails when a slot range does not fit in an `i32`
+    fn try_from(input: IntentParserResult) -> Fallible<Self> {
+        let input_string = CString::new(input.input)?;
+        let slots = CSlotList::try_from(input.slots)?;
+        let alternatives = CIntentParserAlternativeArray::try_from(input.alternatives)?;
+        Ok(Self {
+            input: input_string.into_raw(),
             intent: CIntentClassifierResult::from(input.intent).into_raw_pointer(),
-            slots: CSlotList::from(input.slots).into_raw_pointer(),
-            alternatives: CIntentParserAlternativeArray::from(input.alternatives)
-                .into_raw_pointer(),
-        }
+            slots: slots.into_raw_pointer(),
+            alternatives: alternatives.into_raw_pointer(),
+        })
     }
 }
 
@@ -67,12 +75,16 @@ pub struct CIntentParserAlternative {
     pub slots: *const CSlotList,
 }
 
-impl From<IntentParserAlternative> for CIntentParserAlternative {
-    fn from(input: IntentParserAlternative) -> Self {
-        Self {
+impl TryFrom<IntentParserAlternative> for CIntentParserAlternative {
+    type Error = failure::Error;
+
+    /// Fails when a slot range does not fit in an `i32`
+    fn try_from(input: IntentParserAlternative) -> Fallible<Self> {
+        let slots = CSlotList::try_from(input.slots)?;
+        Ok(Self {
             intent: CIntentClassifierResult::from(input.intent).into_raw_pointer(),
-            slots: CSlotList::from(input.slots).into_raw_pointer(),
-        }
+            slots: slots.into_raw_pointer(),
+        })
     }
 }
 
@@ -102,18 +114,20 @@ pub struct CIntentParserAlternativeArray {
     pub size: i32,
 }
 
-impl From<Vec<IntentParserAlternative>> for CIntentParserAlternativeArray {
-    fn from(input: Vec<IntentParserAlternative>) -> Self {
-        Self {
+impl TryFrom<Vec<IntentParserAlternative>> for CIntentParserAlternativeArray {
+    type Error = failure::Error;
+
+    fn try_from(input: Vec<IntentParserAlternative>) -> Fallible<Self> {
+        Ok(Self {
             size: input.len() as i32,
             intent_parser_alternatives: Box::into_raw(
                 input
                     .into_iter()
-                    .map(CIntentParserAlternative::from)
-                    .collect::<Vec<_>>()
+                    .map(CIntentParserAlternative::try_from)
+                    .collect::<Fallible<Vec<_>>>()?
                     .into_boxed_slice(),
             ) as *const CIntentParserAlternative,
-        }
+        })
     }
 }
 
@@ -246,18 +260,20 @@ pub struct CSlotList {
     pub size: i32, // Note: we can't use `libc::size_t` because it's not supported by JNA
 }
 
-impl From<Vec<Slot>> for CSlotList {
-    fn from(input: Vec<Slot>) -> Self {
-        Self {
+impl TryFrom<Vec<Slot>> for CSlotList {
+    type Error = failure::Error;
+
+    fn try_from(input: Vec<Slot>) -> Fallible<Self> {
+        Ok(Self {
             size: input.len() as i32,
             slots: Box::into_raw(
                 input
                     .into_iter()
-                    .map(CSlot::from)
-                    .collect::<Vec<_>>()
+                    .map(CSlot::try_from)
+                    .collect::<Fallible<Vec<_>>>()?
                     .into_boxed_slice(),
             ) as *const CSlot,
-        }
+        })
     }
 }
 
@@ -285,6 +301,38 @@ impl Drop for CSlotList {
     }
 }
 
+/// Enum describing the unit of the offsets of a range in an input text
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SNIPS_RANGE_UNIT {
+    /// The offsets are counted in Unicode scalar values, which is the unit of slot ranges
+    SNIPS_RANGE_UNIT_CHAR = 1,
+    /// The offsets are counted in UTF-8 bytes
+    SNIPS_RANGE_UNIT_BYTE = 2,
+    /// The offsets are counted in UTF-16 code units, as in JVM strings
+    SNIPS_RANGE_UNIT_UTF16 = 3,
+}
+
+impl From<RangeUnit> for SNIPS_RANGE_UNIT {
+    fn from(value: RangeUnit) -> Self {
+        match value {
+            RangeUnit::Char => SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_CHAR,
+            RangeUnit::Byte => SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_BYTE,
+            RangeUnit::Utf16 => SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_UTF16,
+        }
+    }
+}
+
+impl AsRust<RangeUnit> for SNIPS_RANGE_UNIT {
+    fn as_rust(&self) -> Fallible<RangeUnit> {
+        Ok(match self {
+            SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_CHAR => RangeUnit::Char,
+            SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_BYTE => RangeUnit::Byte,
+            SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_UTF16 => RangeUnit::Utf16,
+        })
+    }
+}
+
 /// Struct describing a Slot
 #[repr(C)]
 #[derive(Debug)]
@@ -299,29 +347,37 @@ pub struct CSlot {
     pub entity: *const libc::c_char,
     /// Name of the slot
     pub slot_name: *const libc::c_char,
-    /// Start index of raw value in input text
+    /// Start char index of raw value in input text
     pub range_start: i32,
-    /// End index of raw value in input text
+    /// End char index of raw value in input text
     pub range_end: i32,
-    /// Confidence score of the slot
+    /// Confidence score of the slot, or -1 when the slot has no confidence score
     pub confidence_score: libc::c_float,
 }
 
-impl From<Slot> for CSlot {
-    fn from(input: Slot) -> Self {
-        Self {
-            raw_value: CString::new(input.raw_value).unwrap().into_raw(),
+impl TryFrom<Slot> for CSlot {
+    type Error = failure::Error;
+
+    /// Fails when the range does not fit in an `i32`
+    fn try_from(input: Slot) -> Fallible<Self> {
+        let (range_start, range_end) = i32_range(&input.range)?;
+        // The strings are checked before any value is leaked into a raw pointer
+        let raw_value = CString::new(input.raw_value)?;
+        let entity = CString::new(input.entity)?;
+        let slot_name = CString::new(input.slot_name)?;
+        Ok(Self {
+            raw_value: raw_value.into_raw(),
             value: CSlotValue::from(input.value).into_raw_pointer(),
             alternatives: CSlotValueArray::from(input.alternatives).into_raw_pointer(),
-            range_start: input.range.start as i32,
-            range_end: input.range.end as i32,
-            entity: CString::new(input.entity).unwrap().into_raw(),
-            slot_name: CString::new(input.slot_name).unwrap().into_raw(),
+            range_start,
+            range_end,
+            entity: entity.into_raw(),
+            slot_name: slot_name.into_raw(),
             confidence_score: input
                 .confidence_score
                 .map(|v| v as libc::c_float)
                 .unwrap_or(-1.),
-        }
+        })
     }
 }
 
@@ -331,7 +387,7 @@ impl AsRust<Slot> for CSlot {
             raw_value: create_rust_string_from!(self.raw_value),
             value: unsafe { &*self.value }.as_rust()?,
             alternatives: unsafe { &*self.alternatives }.as_rust()?,
-            range: (self.range_start as usize..self.range_end as usize),
+            range: usize_range(self.range_start, self.range_end)?,
             entity: create_rust_string_from!(self.entity),
             slot_name: create_rust_string_from!(self.slot_name),
             confidence_score: if self.confidence_score < 0.0 {
@@ -387,34 +443,43 @@ pub enum SNIPS_SLOT_VALUE_TYPE {
     SNIPS_SLOT_VALUE_TYPE_COUNTRY = 14,
     /// Region type represented by a char *
     SNIPS_SLOT_VALUE_TYPE_REGION = 15,
-}
-
-impl<'a> From<&'a SlotValue> for SNIPS_SLOT_VALUE_TYPE {
-    fn from(slot_value: &SlotValue) -> Self {
-        match slot_value {
-            &crate::SlotValue::Custom(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM,
-            &SlotValue::Number(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER,
-            &SlotValue::Ordinal(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_ORDINAL,
-            &SlotValue::InstantTime(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_INSTANTTIME,
-            &SlotValue::TimeInterval(_) => {
+    /// Value of a kind unknown to this version of the ontology, represented by a CUnknownValue
+    SNIPS_SLOT_VALUE_TYPE_UNKNOWN = 16,
+}
+
+impl From<SlotValueKind> for SNIPS_SLOT_VALUE_TYPE {
+    fn from(kind: SlotValueKind) -> Self {
+        match kind {
+            SlotValueKind::Custom => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM,
+            SlotValueKind::Number => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER,
+            SlotValueKind::Ordinal => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_ORDINAL,
+            SlotValueKind::Percentage => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE,
+            SlotValueKind::InstantTime => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_INSTANTTIME,
+            SlotValueKind::TimeInterval => {
                 SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TIMEINTERVAL
             }
-            &SlotValue::AmountOfMoney(_) => {
+            SlotValueKind::AmountOfMoney => {
                 SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_AMOUNTOFMONEY
             }
-            &SlotValue::Temperature(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TEMPERATURE,
-            &SlotValue::Duration(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_DURATION,
-            &SlotValue::Percentage(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE,
-            &SlotValue::MusicAlbum(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM,
-            &SlotValue::MusicArtist(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICARTIST,
-            &SlotValue::MusicTrack(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICTRACK,
-            &SlotValue::City(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CITY,
-            &SlotValue::Country(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_COUNTRY,
-            &SlotValue::Region(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION,
+            SlotValueKind::Temperature => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TEMPERATURE,
+            SlotValueKind::Duration => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_DURATION,
+            SlotValueKind::MusicAlbum => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM,
+            SlotValueKind::MusicArtist => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICARTIST,
+            SlotValueKind::MusicTrack => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICTRACK,
+            SlotValueKind::City => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CITY,
+            SlotValueKind::Country => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_COUNTRY,
+            SlotValueKind::Region => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION,
+            SlotValueKind::Unknown => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_UNKNOWN,
         }
     }
 }
 
+impl<'a> From<&'a SlotValue> for SNIPS_SLOT_VALUE_TYPE {
+    fn from(slot_value: &SlotValue) -> Self {
+        slot_value.kind().into()
+    }
+}
+
 /// Enum describing the precision of a resolved value
 #[repr(C)]
 #[derive(Debug)]
@@ -625,11 +690,59 @@ impl Drop for CAmountOfMoneyValue {
     }
 }
 
+/// Enum describing the unit to which a temperature value is converted
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SNIPS_TEMPERATURE_UNIT {
+    /// The temperature has no unit
+    SNIPS_TEMPERATURE_UNIT_NONE = 0,
+    /// The temperature is in degrees Celsius
+    SNIPS_TEMPERATURE_UNIT_CELSIUS = 1,
+    /// The temperature is in degrees Fahrenheit
+    SNIPS_TEMPERATURE_UNIT_FAHRENHEIT = 2,
+    /// The temperature is in Kelvin
+    SNIPS_TEMPERATURE_UNIT_KELVIN = 3,
+    /// The temperature is in degrees of an unspecified scale
+    SNIPS_TEMPERATURE_UNIT_DEGREE = 4,
+}
+
+impl From<Option<TemperatureUnit>> for SNIPS_TEMPERATURE_UNIT {
+    fn from(value: Option<TemperatureUnit>) -> Self {
+        match value {
+            None => SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_NONE,
+            Some(TemperatureUnit::Celsius) => {
+                SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_CELSIUS
+            }
+            Some(TemperatureUnit::Fahrenheit) => {
+                SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_FAHRENHEIT
+            }
+            Some(TemperatureUnit::Kelvin) => SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_KELVIN,
+            Some(TemperatureUnit::Degree) => SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_DEGREE,
+        }
+    }
+}
+
+impl AsRust<Option<TemperatureUnit>> for SNIPS_TEMPERATURE_UNIT {
+    fn as_rust(&self) -> Fallible<Option<TemperatureUnit>> {
+        Ok(match self {
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_NONE => None,
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_CELSIUS => {
+                Some(TemperatureUnit::Celsius)
+            }
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_FAHRENHEIT => {
+                Some(TemperatureUnit::Fahrenheit)
+            }
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_KELVIN => Some(TemperatureUnit::Kelvin),
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_DEGREE => Some(TemperatureUnit::Degree),
+        })
+    }
+}
+
 /// Representation of a temperature value
 #[repr(C)]
 #[derive(Debug)]
 pub struct CTemperatureValue {
-    /// The unit used
+    /// The unit used, as found in the input, or null when missing
     pub unit: *const libc::c_char,
     /// The temperature resolved
     pub value: libc::c_float,
@@ -663,6 +776,23 @@ impl Drop for CTemperatureValue {
     }
 }
 
+/// Converts the temperature pointed by `temperature` to `unit` and writes the converted value
+/// in `result`
+///
+/// The unit of the temperature is parsed leniently, see `TemperatureValue::parsed_unit`.
+pub fn convert_temperature_value(
+    temperature: *const CTemperatureValue,
+    unit: SNIPS_TEMPERATURE_UNIT,
+    result: *mut libc::c_float,
+) -> Fallible<()> {
+    let temperature = borrow_non_null(temperature, "temperature")?.as_rust()?;
+    let unit = unit
+        .as_rust()?
+        .ok_or_else(|| format_err!("Cannot convert temperature to an empty unit"))?;
+    let converted = temperature.to_unit(unit)?;
+    write_non_null(result, converted.value as libc::c_float, "result")
+}
+
 /// Representation of a duration value
 #[repr(C)]
 #[derive(Debug)]
@@ -719,13 +849,49 @@ impl AsRust<DurationValue> for CDurationValue {
     }
 }
 
+/// Representation of a slot value of a kind unknown to this version of the ontology
+#[repr(C)]
+#[derive(Debug)]
+pub struct CUnknownValue {
+    /// The kind of the value, such as "Fortnight"
+    pub kind: *const libc::c_char,
+    /// JSON object holding the other fields of the value
+    pub payload: *const libc::c_char,
+}
+
+impl From<UnknownSlotValue> for CUnknownValue {
+    fn from(value: UnknownSlotValue) -> Self {
+        let payload = serde_json::Value::Object(value.payload).to_string();
+        Self {
+            kind: CString::new(value.kind).unwrap().into_raw(),
+            payload: CString::new(payload).unwrap().into_raw(),
+        }
+    }
+}
+
+impl AsRust<UnknownSlotValue> for CUnknownValue {
+    fn as_rust(&self) -> Fallible<UnknownSlotValue> {
+        Ok(UnknownSlotValue {
+            kind: create_rust_string_from!(self.kind),
+            payload: serde_json::from_str(&create_rust_string_from!(self.payload))?,
+        })
+    }
+}
+
+impl Drop for CUnknownValue {
+    fn drop(&mut self) {
+        take_back_c_string!(self.kind);
+        take_back_c_string!(self.payload);
+    }
+}
+
 /// A slot value
 #[repr(C)]
 #[derive(Debug)]
 pub struct CSlotValue {
     /// Points to either a *const char, a CNumberValue, a COrdinalValue,
     /// a CInstantTimeValue, a CTimeIntervalValue, a CAmountOfMoneyValue,
-    /// a CTemperatureValue or a CDurationValue depending on value_type
+    /// a CTemperatureValue, a CDurationValue or a CUnknownValue depending on value_type
     value: *const libc::c_void,
     /// The type of the value
     value_type: SNIPS_SLOT_VALUE_TYPE,
@@ -750,6 +916,7 @@ impl From<SlotValue> for CSlotValue {
             SlotValue::City(v) => CString::new(v.value).unwrap().into_raw() as _,
             SlotValue::Country(v) => CString::new(v.value).unwrap().into_raw() as _,
             SlotValue::Region(v) => CString::new(v.value).unwrap().into_raw() as _,
+            SlotValue::Unknown(v) => CUnknownValue::from(v).into_raw_pointer() as _,
         };
         Self { value_type, value }
     }
@@ -799,6 +966,12 @@ impl AsRust<SlotValue> for CSlotValue {
                 let duration_value = c_duration_value.as_rust()?;
                 Ok(SlotValue::Duration(duration_value))
             }
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE => {
+                let percentage_value: f64 = unsafe { *(self.value as *const CPercentageValue) };
+                Ok(SlotValue::Percentage(PercentageValue {
+                    value: percentage_value,
+                }))
+            }
             SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM => Ok(SlotValue::MusicAlbum(
                 create_rust_string_from!(self.value as *const libc::c_char).into(),
             )),
@@ -817,10 +990,11 @@ impl AsRust<SlotValue> for CSlotValue {
             SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION => Ok(SlotValue::Region(
                 create_rust_string_from!(self.value as *const libc::c_char).into(),
             )),
-            _ => bail!(
-                "Unknown slot value type: {:?}. Cannot perform conversion to Rust object.",
-                self.value_type
-            ),
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_UNKNOWN => {
+                let c_unknown_value = unsafe { &*(self.value as *const CUnknownValue) };
+                let unknown_value = c_unknown_value.as_rust()?;
+                Ok(SlotValue::Unknown(unknown_value))
+            }
         }
     }
 }
@@ -874,6 +1048,9 @@ impl Drop for CSlotValue {
                 SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION => {
                     CString::drop_raw_pointer(self.value)
                 }
+                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_UNKNOWN => {
+                    CUnknownValue::drop_raw_pointer(self.value as _)
+                }
             }
         };
     }
@@ -929,16 +1106,245 @@ impl Drop for CSlotValueArray {
     }
 }
 
+/// Creates a `CIntentParserResult` owned by the library, whose fields are copied from the given
+/// pointers which remain owned by the caller
+///
+/// `alternatives` may be null, in which case the result has no alternatives.
+pub fn create_intent_parser_result(
+    input: *const libc::c_char,
+    intent: *const CIntentClassifierResult,
+    slots: *const CSlotList,
+    alternatives: *const CIntentParserAlternativeArray,
+    result: *mut *const CIntentParserResult,
+) -> Fallible<()> {
+    let intent_parser_result = IntentParserResult {
+        input: str_from_non_null(input, "input")?.to_string(),
+        intent: borrow_non_null(intent, "intent")?.as_rust()?,
+        slots: borrow_non_null(slots, "slots")?.as_rust()?,
+        alternatives: borrow_nullable(alternatives)
+            .map(|alternatives| alternatives.as_rust())
+            .transpose()?
+            .unwrap_or_default(),
+    };
+    point_to_c_value::<_, CIntentParserResult>(intent_parser_result, result)
+}
+
+pub fn clone_intent_parser_result(
+    intent_parser_result: *const CIntentParserResult,
+    result: *mut *const CIntentParserResult,
+) -> Fallible<()> {
+    clone_c_value(intent_parser_result, result)
+}
+
+/// Creates a copy of the `CIntentParserResult`, owned by the library, whose slot ranges are
+/// converted from the `from` unit into the `to` unit
+pub fn convert_intent_parser_result_ranges(
+    intent_parser_result: *const CIntentParserResult,
+    from: SNIPS_RANGE_UNIT,
+    to: SNIPS_RANGE_UNIT,
+    result: *mut *const CIntentParserResult,
+) -> Fallible<()> {
+    let intent_parser_result = borrow_non_null(intent_parser_result, "intent_parser_result")?
+        .as_rust()?
+        .convert_ranges(from.as_rust()?, to.as_rust()?)?;
+    point_to_c_value::<_, CIntentParserResult>(intent_parser_result, result)
+}
+
+pub fn destroy_intent_parser_result(
+    intent_parser_result: *mut CIntentParserResult,
+) -> Fallible<()> {
+    destroy_non_null(intent_parser_result, "intent_parser_result")
+}
+
+/// Creates a `CSlot` owned by the library, whose fields are copied from the given pointers
+/// which remain owned by the caller
+///
+/// `alternatives` may be null, in which case the slot has no alternatives. Any negative
+/// `confidence_score`, such as the -1 used by `CSlot`, means that the slot has no confidence
+/// score. The creation fails when the range is negative or when its start is after its end.
+#[allow(clippy::too_many_arguments)]
+pub fn create_slot(
+    raw_value: *const libc::c_char,
+    value: *const CSlotValue,
+    alternatives: *const CSlotValueArray,
+    entity: *const libc::c_char,
+    slot_name: *const libc::c_char,
+    range_start: i32,
+    range_end: i32,
+    confidence_score: libc::c_float,
+    result: *mut *const CSlot,
+) -> Fallible<()> {
+    let slot = Slot {
+        raw_value: str_from_non_null(raw_value, "raw_value")?.to_string(),
+        value: borrow_non_null(value, "value")?.as_rust()?,
+        alternatives: borrow_nullable(alternatives)
+            .map(|alternatives| alternatives.as_rust())
+            .transpose()?
+            .unwrap_or_default(),
+        range: usize_range(range_start, range_end)?,
+        entity: str_from_non_null(entity, "entity")?.to_string(),
+        slot_name: str_from_non_null(slot_name, "slot_name")?.to_string(),
+        confidence_score: if confidence_score < 0.0 {
+            None
+        } else {
+            Some(confidence_score)
+        },
+    };
+    point_to_c_value::<_, CSlot>(slot, result)
+}
+
+pub fn clone_slot(slot: *const CSlot, result: *mut *const CSlot) -> Fallible<()> {
+    clone_c_value(slot, result)
+}
+
+pub fn destroy_slot(slot: *mut CSlot) -> Fallible<()> {
+    destroy_non_null(slot, "slot")
+}
+
+/// Creates a `CSlotValue` owned by the library from a value of the given type, which remains
+/// owned by the caller
+pub fn create_slot_value(
+    value_type: SNIPS_SLOT_VALUE_TYPE,
+    value: *const libc::c_void,
+    result: *mut *const CSlotValue,
+) -> Fallible<()> {
+    borrow_non_null(value, "value")?;
+    // The value is owned by the caller so the borrowed slot value must not be dropped
+    let borrowed_slot_value = ManuallyDrop::new(CSlotValue { value, value_type });
+    let slot_value = borrowed_slot_value.as_rust()?;
+    point_to_c_value::<_, CSlotValue>(slot_value, result)
+}
+
+pub fn clone_slot_value(
+    slot_value: *const CSlotValue,
+    result: *mut *const CSlotValue,
+) -> Fallible<()> {
+    clone_c_value(slot_value, result)
+}
+
+pub fn destroy_slot_value(slot_value: *mut CSlotValue) -> Fallible<()> {
+    destroy_non_null(slot_value, "slot_value")
+}
+
+pub fn intent_parser_result_from_json(
+    json: *const libc::c_char,
+    result: *mut *const CIntentParserResult,
+) -> Fallible<()> {
+    let json = str_from_non_null(json, "json")?;
+    let intent_parser_result: IntentParserResult = serde_json::from_str(json)
+        .with_context(|_| "Cannot deserialize intent parser result from JSON")?;
+    point_to_c_value::<_, CIntentParserResult>(intent_parser_result, result)
+}
+
+pub fn intent_parser_result_to_json(
+    intent_parser_result: *const CIntentParserResult,
+    json: *mut *const libc::c_char,
+) -> Fallible<()> {
+    let intent_parser_result = borrow_non_null(intent_parser_result, "intent_parser_result")?;
+    let json_string = serde_json::to_string(&intent_parser_result.as_rust()?)?;
+    point_to_c_string(json, json_string, "json")
+}
+
+fn borrow_non_null<'a, T>(pointer: *const T, name: &str) -> Fallible<&'a T> {
+    if pointer.is_null() {
+        bail!("Unexpected null pointer: {}", name)
+    }
+    Ok(unsafe { &*pointer })
+}
+
+fn borrow_nullable<'a, T>(pointer: *const T) -> Option<&'a T> {
+    unsafe { pointer.as_ref() }
+}
+
+pub(crate) fn str_from_non_null<'a>(pointer: *const libc::c_char, name: &str) -> Fallible<&'a str> {
+    if pointer.is_null() {
+        bail!("Unexpected null pointer: {}", name)
+    }
+    Ok(unsafe { CStr::from_ptr(pointer) }.to_str()?)
+}
+
+fn destroy_non_null<T>(pointer: *mut T, name: &str) -> Fallible<()> {
+    if pointer.is_null() {
+        bail!("Unexpected null pointer: {}", name)
+    }
+    unsafe { T::from_raw_pointer_mut(pointer) }?;
+    Ok(())
+}
+
+/// Converts the bounds of a range received through the FFI, which must be positive and ordered
+pub(crate) fn usize_range(start: i32, end: i32) -> Fallible<Range<usize>> {
+    match (usize::try_from(start), usize::try_from(end)) {
+        (Ok(start), Ok(end)) if start <= end => Ok(start..end),
+        _ => bail!("Invalid range: {}..{}", start, end),
+    }
+}
+
+/// Returns the output pointed by `pointer`, which the caller must have allocated
+pub(crate) fn non_null_output<'a, T>(pointer: *mut T, name: &str) -> Fallible<&'a mut T> {
+    if pointer.is_null() {
+        bail!("Unexpected null pointer: {}", name)
+    }
+    Ok(unsafe { &mut *pointer })
+}
+
+/// Converts the bounds of a range sent through the FFI, which must fit in an `i32`
+pub(crate) fn i32_range(range: &Range<usize>) -> Fallible<(i32, i32)> {
+    match (i32::try_from(range.start), i32::try_from(range.end)) {
+        (Ok(start), Ok(end)) => Ok((start, end)),
+        _ => bail!(
+            "Range too large for the FFI: {}..{}",
+            range.start,
+            range.end
+        ),
+    }
+}
+
+fn write_non_null<T>(pointer: *mut T, value: T, name: &str) -> Fallible<()> {
+    *non_null_output(pointer, name)? = value;
+    Ok(())
+}
+
+/// Points `pointer` to a C string owned by the library, see `destroy_string`
+pub(crate) fn point_to_c_string(
+    pointer: *mut *const libc::c_char,
+    value: String,
+    name: &str,
+) -> Fallible<()> {
+    let output = non_null_output(pointer, name)?;
+    *output = CString::new(value)?.into_raw();
+    Ok(())
+}
+
+fn clone_c_value<R, C>(input: *const C, output: *mut *const C) -> Fallible<()>
+where
+    C: AsRust<R> + TryFrom<R>,
+    C::Error: Into<failure::Error>,
+{
+    let value = borrow_non_null(input, "input")?.as_rust()?;
+    point_to_c_value::<R, C>(value, output)
+}
+
+fn point_to_c_value<R, C>(value: R, output: *mut *const C) -> Fallible<()>
+where
+    C: TryFrom<R>,
+    C::Error: Into<failure::Error>,
+{
+    let output = non_null_output(output, "result")?;
+    *output = C::try_from(value).map_err(Into::into)?.into_raw_pointer();
+    Ok(())
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     pub fn round_trip_test<T, U>(input: T)
     where
         T: Clone + PartialEq + std::fmt::Debug,
-        U: From<T> + AsRust<T>,
+        U: TryFrom<T> + AsRust<T>,
+        U::Error: std::fmt::Debug,
     {
-        let c = U::from(input.clone());
+        let c = U::try_from(input.clone()).expect("could not convert to C");
 
         let result = c.as_rust().expect("could not convert back to rust");
         assert_eq!(result, input);
@@ -964,9 +1370,40 @@ mod tests {
         round_trip_test::<_, CTemperatureValue>(TemperatureValue {
             value: 20.0,
             unit: Some("°C".to_string()),
+        });
+        round_trip_test::<_, CTemperatureValue>(TemperatureValue {
+            value: 20.0,
+            unit: None,
         })
     }
 
+    #[test]
+    fn convert_c_temperature_value() {
+        // Given
+        let temperature = CTemperatureValue::from(TemperatureValue {
+            value: 20.0,
+            unit: Some("°C".to_string()),
+        });
+        let mut converted: libc::c_float = 0.0;
+
+        // When
+        convert_temperature_value(
+            &temperature,
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_FAHRENHEIT,
+            &mut converted,
+        )
+        .unwrap();
+
+        // Then
+        assert!((converted - 68.0).abs() < 1e-3);
+        assert!(convert_temperature_value(
+            &temperature,
+            SNIPS_TEMPERATURE_UNIT::SNIPS_TEMPERATURE_UNIT_NONE,
+            &mut converted,
+        )
+        .is_err());
+    }
+
     #[test]
     fn round_trip_c_amount_of_value() {
         round_trip_test::<_, CAmountOfMoneyValue>(AmountOfMoneyValue {
@@ -1072,6 +1509,43 @@ mod tests {
     fn round_trip_c_slot_value() {
         round_trip_test::<_, CSlotValue>(SlotValue::Custom("foobar".to_string().into()));
         round_trip_test::<_, CSlotValue>(SlotValue::Number(NumberValue { value: 42.0 }));
+        round_trip_test::<_, CSlotValue>(SlotValue::Percentage(PercentageValue { value: 20.0 }));
+        round_trip_test::<_, CSlotValue>(
+            lenient::from_str(r#"{"kind": "Fortnight", "count": 1}"#).unwrap(),
+        );
+    }
+
+    #[test]
+    fn slot_value_type_matches_slot_value_kind() {
+        // Given
+        let values = vec![
+            SlotValue::Custom("foobar".to_string().into()),
+            SlotValue::Percentage(PercentageValue { value: 20.0 }),
+            SlotValue::MusicAlbum("Discovery".to_string().into()),
+            SlotValue::Region("California".to_string().into()),
+        ];
+
+        // When
+        let value_types = values
+            .iter()
+            .map(SNIPS_SLOT_VALUE_TYPE::from)
+            .collect::<Vec<_>>();
+
+        // Then
+        let expected_value_types = vec![
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM,
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE,
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM,
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION,
+        ];
+        assert_eq!(expected_value_types, value_types);
+        for value in values {
+            let c_value = CSlotValue::from(value.clone());
+            assert_eq!(
+                SNIPS_SLOT_VALUE_TYPE::from(value.kind()),
+                c_value.value_type
+            );
+        }
     }
 
     #[test]
@@ -1227,4 +1701,247 @@ mod tests {
             },
         ]);
     }
+
+    fn intent_parser_result() -> IntentParserResult {
+        IntentParserResult {
+            input: "turn the heat to 21 degrees".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("SetTemperature".to_string()),
+                confidence_score: 0.8,
+            },
+            slots: vec![Slot {
+                raw_value: "21 degrees".to_string(),
+                value: SlotValue::Temperature(TemperatureValue {
+                    value: 21.0,
+                    unit: Some("degree".to_string()),
+                }),
+                alternatives: vec![],
+                range: 17..27,
+                entity: "snips/temperature".to_string(),
+                slot_name: "temperature".to_string(),
+                confidence_score: Some(0.9),
+            }],
+            alternatives: vec![],
+        }
+    }
+
+    #[test]
+    fn intent_parser_result_json_round_trip() {
+        // Given
+        let json = serde_json::to_string(&intent_parser_result()).unwrap();
+        let c_json = CString::new(json.clone()).unwrap();
+        let mut c_result: *const CIntentParserResult = null();
+        let mut c_json_result: *const libc::c_char = null();
+
+        // When
+        intent_parser_result_from_json(c_json.as_ptr(), &mut c_result).unwrap();
+        intent_parser_result_to_json(c_result, &mut c_json_result).unwrap();
+
+        // Then
+        let c_json_result = unsafe { CString::from_raw(c_json_result as *mut libc::c_char) };
+        assert_eq!(json, c_json_result.to_str().unwrap());
+        assert_eq!(
+            intent_parser_result(),
+            unsafe { &*c_result }.as_rust().unwrap()
+        );
+        destroy_intent_parser_result(c_result as *mut _).unwrap();
+    }
+
+    #[test]
+    fn convert_intent_parser_result_ranges_to_utf16() {
+        // Given
+        let mut result = intent_parser_result();
+        result.input = "🔥 turn the heat to 21 degrees".to_string();
+        result.slots[0].range = 19..29;
+        let c_result = CIntentParserResult::try_from(result.clone()).unwrap();
+        let mut c_converted: *const CIntentParserResult = null();
+
+        // When
+        convert_intent_parser_result_ranges(
+            &c_result,
+            SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_CHAR,
+            SNIPS_RANGE_UNIT::SNIPS_RANGE_UNIT_UTF16,
+            &mut c_converted,
+        )
+        .unwrap();
+
+        // Then
+        let converted = unsafe { &*c_converted }.as_rust().unwrap();
+        assert_eq!(20..30, converted.slots[0].range);
+        assert_eq!(result.input, converted.input);
+        destroy_intent_parser_result(c_converted as *mut _).unwrap();
+    }
+
+    #[test]
+    fn invalid_intent_parser_result_json_is_rejected() {
+        // Given
+        let c_json = CString::new(r#"{"input": "foo"}"#).unwrap();
+        let mut c_result: *const CIntentParserResult = null();
+
+        // When
+        let result = intent_parser_result_from_json(c_json.as_ptr(), &mut c_result);
+
+        // Then
+        assert!(result.is_err());
+        assert!(c_result.is_null());
+    }
+
+    #[test]
+    fn range_above_i32_max_is_rejected() {
+        // Given
+        let mut result = intent_parser_result();
+        result.slots[0].range = 0..i32::MAX as usize + 1;
+        let c_json = CString::new(serde_json::to_string(&result).unwrap()).unwrap();
+        let mut c_result: *const CIntentParserResult = null();
+
+        // When
+        let from_json = intent_parser_result_from_json(c_json.as_ptr(), &mut c_result);
+        let c_slot = CSlot::try_from(result.slots[0].clone());
+
+        // Then
+        assert_eq!(
+            "Range too large for the FFI: 0..2147483648",
+            from_json.unwrap_err().to_string()
+        );
+        assert!(c_result.is_null());
+        assert!(c_slot.is_err());
+    }
+
+    #[test]
+    fn create_intent_parser_result_from_caller_owned_values() {
+        // Given
+        let expected_result = intent_parser_result();
+        let input = CString::new(expected_result.input.clone()).unwrap();
+        let intent = CIntentClassifierResult::from(expected_result.intent.clone());
+        let slots = CSlotList::try_from(expected_result.slots.clone()).unwrap();
+        let mut c_result: *const CIntentParserResult = null();
+        let mut c_clone: *const CIntentParserResult = null();
+
+        // When
+        create_intent_parser_result(input.as_ptr(), &intent, &slots, null(), &mut c_result)
+            .unwrap();
+        clone_intent_parser_result(c_result, &mut c_clone).unwrap();
+        destroy_intent_parser_result(c_result as *mut _).unwrap();
+
+        // Then
+        assert_eq!(expected_result, unsafe { &*c_clone }.as_rust().unwrap());
+        destroy_intent_parser_result(c_clone as *mut _).unwrap();
+    }
+
+    #[test]
+    fn create_slot_from_caller_owned_values() {
+        // Given
+        let expected_slot = intent_parser_result().slots[0].clone();
+        let raw_value = CString::new(expected_slot.raw_value.clone()).unwrap();
+        let value = CSlotValue::from(expected_slot.value.clone());
+        let entity = CString::new(expected_slot.entity.clone()).unwrap();
+        let slot_name = CString::new(expected_slot.slot_name.clone()).unwrap();
+        let mut c_slot: *const CSlot = null();
+
+        // When
+        create_slot(
+            raw_value.as_ptr(),
+            &value,
+            null(),
+            entity.as_ptr(),
+            slot_name.as_ptr(),
+            17,
+            27,
+            0.9,
+            &mut c_slot,
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(expected_slot, unsafe { &*c_slot }.as_rust().unwrap());
+        destroy_slot(c_slot as *mut _).unwrap();
+    }
+
+    #[test]
+    fn create_slot_with_invalid_range_is_rejected() {
+        // Given
+        let raw_value = CString::new("tomorrow").unwrap();
+        let value = CSlotValue::from(SlotValue::Custom("tomorrow".into()));
+        let entity = CString::new("day").unwrap();
+        let slot_name = CString::new("date").unwrap();
+        let ranges = vec![(-1, 8), (0, -8), (8, 0)];
+
+        for (range_start, range_end) in ranges {
+            let mut c_slot: *const CSlot = null();
+
+            // When
+            let result = create_slot(
+                raw_value.as_ptr(),
+                &value,
+                null(),
+                entity.as_ptr(),
+                slot_name.as_ptr(),
+                range_start,
+                range_end,
+                -1.0,
+                &mut c_slot,
+            );
+
+            // Then
+            assert_eq!(
+                format!("Invalid range: {}..{}", range_start, range_end),
+                result.unwrap_err().to_string()
+            );
+            assert!(c_slot.is_null());
+        }
+    }
+
+    #[test]
+    fn create_slot_value_from_caller_owned_value() {
+        // Given
+        let number: CNumberValue = 42.0;
+        let mut c_slot_value: *const CSlotValue = null();
+        let mut c_clone: *const CSlotValue = null();
+
+        // When
+        create_slot_value(
+            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER,
+            &number as *const CNumberValue as *const libc::c_void,
+            &mut c_slot_value,
+        )
+        .unwrap();
+        clone_slot_value(c_slot_value, &mut c_clone).unwrap();
+        destroy_slot_value(c_slot_value as *mut _).unwrap();
+
+        // Then
+        assert_eq!(
+            SlotValue::Number(NumberValue { value: 42.0 }),
+            unsafe { &*c_clone }.as_rust().unwrap()
+        );
+        assert_eq!(42.0, number);
+        destroy_slot_value(c_clone as *mut _).unwrap();
+    }
+
+    #[test]
+    fn null_output_pointers_are_rejected() {
+        // Given
+        let json = serde_json::to_string(&intent_parser_result()).unwrap();
+        let c_json = CString::new(json).unwrap();
+        let c_result = CIntentParserResult::try_from(intent_parser_result()).unwrap();
+        let c_slot_value = CSlotValue::from(SlotValue::Number(NumberValue { value: 42.0 }));
+
+        // When
+        let from_json = intent_parser_result_from_json(c_json.as_ptr(), std::ptr::null_mut());
+        let to_json = intent_parser_result_to_json(&c_result, std::ptr::null_mut());
+        let clone = clone_slot_value(&c_slot_value, std::ptr::null_mut());
+
+        // Then
+        assert_eq!(
+            "Unexpected null pointer: result",
+            from_json.unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Unexpected null pointer: json",
+            to_json.unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Unexpected null pointer: result",
+            clone.unwrap_err().to_string()
+        );
+    }
 }
@@ -1,3 +1,4 @@
+// Generated by doc/build.rs from the Rust ontology types, do not edit by hand
 package ai.snips.nlu.ontology
 
 import ai.snips.nlu.ontology.SlotValue.AmountOfMoneyValue
@@ -30,6 +31,7 @@ import ai.snips.nlu.ontology.SlotValue.Type.PERCENTAGE
 import ai.snips.nlu.ontology.SlotValue.Type.REGION
 import ai.snips.nlu.ontology.SlotValue.Type.TEMPERATURE
 import ai.snips.nlu.ontology.SlotValue.Type.TIME_INTERVAL
+import ai.snips.nlu.ontology.SlotValue.Type.UNKNOWN
 import com.fasterxml.jackson.annotation.JsonIgnore
 import com.fasterxml.jackson.annotation.JsonProperty
 import com.fasterxml.jackson.annotation.JsonSubTypes
@@ -41,35 +43,39 @@ import org.parceler.ParcelConstructor
 import org.parceler.ParcelProperty
 
 @Parcel(BEAN)
-data class Range @ParcelConstructor constructor(@ParcelProperty("start") val start: Int,
-                                                @ParcelProperty("end") val end: Int)
+data class IntentParserResult @ParcelConstructor constructor(
+        @ParcelProperty("input") val input: String,
+        @ParcelProperty("intent") val intent: IntentClassifierResult,
+        @ParcelProperty("slots") val slots: List<Slot>,
+        @ParcelProperty("alternatives") val alternatives: List<IntentParserAlternative>)
 
 @Parcel(BEAN)
-data class Slot @ParcelConstructor constructor(@ParcelProperty("rawValue") val rawValue: String,
-                                               @ParcelProperty("value") val value: SlotValue,
-                                               //use a MutableList here to make parceler happy
-                                               @ParcelProperty("alternatives") val alternatives: MutableList<SlotValue>,
-                                               @ParcelProperty("range") val range: Range,
-                                               @ParcelProperty("entity") val entity: String,
-                                               @ParcelProperty("slotName") val slotName: String,
-                                               @ParcelProperty("confidenceScore") val confidenceScore: Float?)
-
-enum class Precision { APPROXIMATE, EXACT }
+data class IntentClassifierResult @ParcelConstructor constructor(
+        @ParcelProperty("intentName") val intentName: String?,
+        @ParcelProperty("confidenceScore") val confidenceScore: Float)
 
-enum class Grain { YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE, SECOND }
+@Parcel(BEAN)
+data class Slot @ParcelConstructor constructor(
+        @ParcelProperty("rawValue") val rawValue: String,
+        @ParcelProperty("value") val value: SlotValue,
+        //use a MutableList here to make parceler happy
+        @ParcelProperty("alternatives") val alternatives: MutableList<SlotValue>,
+        @ParcelProperty("range") val range: Range,
+        @ParcelProperty("entity") val entity: String,
+        @ParcelProperty("slotName") val slotName: String,
+        @ParcelProperty("confidenceScore") val confidenceScore: Float?)
 
-// TODO : add converters to JSR310 / ThreeTen types
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.EXISTING_PROPERTY, property = "kind")
 @JsonSubTypes(
         Type(value = CustomValue::class, name = "Custom"),
         Type(value = NumberValue::class, name = "Number"),
         Type(value = OrdinalValue::class, name = "Ordinal"),
+        Type(value = PercentageValue::class, name = "Percentage"),
         Type(value = InstantTimeValue::class, name = "InstantTime"),
         Type(value = TimeIntervalValue::class, name = "TimeInterval"),
         Type(value = AmountOfMoneyValue::class, name = "AmountOfMoney"),
         Type(value = TemperatureValue::class, name = "Temperature"),
         Type(value = DurationValue::class, name = "Duration"),
-        Type(value = PercentageValue::class, name = "Percentage"),
         Type(value = MusicAlbumValue::class, name = "MusicAlbum"),
         Type(value = MusicArtistValue::class, name = "MusicArtist"),
         Type(value = MusicTrackValue::class, name = "MusicTrack"),
@@ -88,18 +94,19 @@ sealed class SlotValue(val kind: Type) {
         @JsonProperty("Custom") CUSTOM,
         @JsonProperty("Number") NUMBER,
         @JsonProperty("Ordinal") ORDINAL,
+        @JsonProperty("Percentage") PERCENTAGE,
         @JsonProperty("InstantTime") INSTANT_TIME,
         @JsonProperty("TimeInterval") TIME_INTERVAL,
         @JsonProperty("AmountOfMoney") AMOUNT_OF_MONEY,
         @JsonProperty("Temperature") TEMPERATURE,
         @JsonProperty("Duration") DURATION,
-        @JsonProperty("Percentage") PERCENTAGE,
         @JsonProperty("MusicAlbum") MUSICALBUM,
         @JsonProperty("MusicArtist") MUSICARTIST,
         @JsonProperty("MusicTrack") MUSICTRACK,
         @JsonProperty("City") CITY,
         @JsonProperty("Country") COUNTRY,
-        @JsonProperty("Region") REGION
+        @JsonProperty("Region") REGION,
+        UNKNOWN
     }
 
     @Parcel(BEAN)
@@ -109,10 +116,10 @@ sealed class SlotValue(val kind: Type) {
     data class NumberValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Double) : SlotValue(NUMBER)
 
     @Parcel(BEAN)
-    data class PercentageValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Double) : SlotValue(PERCENTAGE)
+    data class OrdinalValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Long) : SlotValue(ORDINAL)
 
     @Parcel(BEAN)
-    data class OrdinalValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Long) : SlotValue(ORDINAL)
+    data class PercentageValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Double) : SlotValue(PERCENTAGE)
 
     @Parcel(BEAN)
     data class InstantTimeValue @ParcelConstructor constructor(
@@ -165,21 +172,23 @@ sealed class SlotValue(val kind: Type) {
 
     @Parcel(BEAN)
     data class RegionValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(REGION)
+
+    @Parcel(BEAN)
+    data class UnknownValue @ParcelConstructor constructor(
+            @ParcelProperty("kindName") val kindName: String,
+            @ParcelProperty("payloadJson") val payloadJson: String) : SlotValue(UNKNOWN)
 }
 
+enum class Grain { YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE, SECOND }
+
+enum class Precision { APPROXIMATE, EXACT }
+
 @Parcel(BEAN)
-data class IntentClassifierResult @ParcelConstructor constructor(
-        @ParcelProperty("intentName") val intentName: String?,
-        @ParcelProperty("confidenceScore") val confidenceScore: Float)
+data class Range @ParcelConstructor constructor(
+        @ParcelProperty("start") val start: Int,
+        @ParcelProperty("end") val end: Int)
 
 @Parcel(BEAN)
 data class IntentParserAlternative @ParcelConstructor constructor(
         @ParcelProperty("intent") val intent: IntentClassifierResult,
         @ParcelProperty("slots") val slots: List<Slot>)
-
-@Parcel(BEAN)
-data class IntentParserResult @ParcelConstructor constructor(
-        @ParcelProperty("input") val input: String,
-        @ParcelProperty("intent") val intent: IntentClassifierResult,
-        @ParcelProperty("slots") val slots: List<Slot>,
-        @ParcelProperty("alternatives") val alternatives: List<IntentParserAlternative>)
@@ -23,6 +23,7 @@ import ai.snips.nlu.ontology.SlotValue.RegionValue
 import ai.snips.nlu.ontology.SlotValue.OrdinalValue
 import ai.snips.nlu.ontology.SlotValue.TemperatureValue
 import ai.snips.nlu.ontology.SlotValue.TimeIntervalValue
+import ai.snips.nlu.ontology.SlotValue.UnknownValue
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
 import com.sun.jna.toJnaPointer
@@ -205,6 +206,7 @@ class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
         const val CITY = 13
         const val COUNTRY = 14
         const val REGION = 15
+        const val UNKNOWN = 16
     }
 
     @JvmField var value_type: Int? = null
@@ -232,6 +234,7 @@ class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
         CITY -> CityValue(value.readString())
         COUNTRY -> CountryValue(value.readString())
         REGION -> RegionValue(value.readString())
+        UNKNOWN -> CUnknownValue(value!!).toUnknownValue()
         else -> throw IllegalArgumentException("unknown value type $value_type")
     }
 }
@@ -303,6 +306,22 @@ class CTemperatureValue(p: Pointer) : Structure(p), Structure.ByReference {
 
 }
 
+class CUnknownValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var kind: Pointer? = null
+    @JvmField var payload: Pointer? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("kind", "payload")
+
+    fun toUnknownValue() = UnknownValue(kindName = kind.readString(),
+                                        payloadJson = payload.readString())
+
+}
+
 class CDurationValue(p: Pointer) : Structure(p), Structure.ByReference {
 
     @JvmField var years: Long? = null
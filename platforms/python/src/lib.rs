@@ -0,0 +1,6 @@
+use pyo3::prelude::*;
+
+#[pymodule]
+fn snips_nlu_ontology(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    ontology::python::register(module)
+}
@@ -0,0 +1,204 @@
+// Generated by doc/build.rs from the Rust ontology types, do not edit by hand
+
+export type Language =
+    | "de"
+    | "en"
+    | "es"
+    | "fr"
+    | "it"
+    | "pt_pt"
+    | "pt_br"
+    | "ja"
+    | "ko";
+
+export type BuiltinEntityKind =
+    | "snips/amountOfMoney"
+    | "snips/duration"
+    | "snips/number"
+    | "snips/ordinal"
+    | "snips/temperature"
+    | "snips/datetime"
+    | "snips/date"
+    | "snips/time"
+    | "snips/datePeriod"
+    | "snips/timePeriod"
+    | "snips/percentage"
+    | "snips/musicAlbum"
+    | "snips/musicArtist"
+    | "snips/musicTrack"
+    | "snips/city"
+    | "snips/country"
+    | "snips/region";
+
+export type GrammarEntityKind =
+    | "snips/amountOfMoney"
+    | "snips/duration"
+    | "snips/number"
+    | "snips/ordinal"
+    | "snips/temperature"
+    | "snips/datetime"
+    | "snips/date"
+    | "snips/time"
+    | "snips/datePeriod"
+    | "snips/timePeriod"
+    | "snips/percentage";
+
+export type BuiltinGazetteerEntityKind =
+    | "snips/city"
+    | "snips/country"
+    | "snips/musicAlbum"
+    | "snips/musicArtist"
+    | "snips/musicTrack"
+    | "snips/region";
+
+export interface IntentParserResult {
+    input: string;
+    intent: IntentClassifierResult;
+    slots: Slot[];
+    alternatives: IntentParserAlternative[];
+}
+
+export interface IntentClassifierResult {
+    intentName: string | null;
+    confidenceScore: number;
+}
+
+export interface Slot {
+    rawValue: string;
+    value: SlotValue;
+    alternatives: SlotValue[];
+    range: Range;
+    entity: string;
+    slotName: string;
+    confidenceScore?: number | null;
+}
+
+export type SlotValue =
+    | CustomValue
+    | NumberValue
+    | OrdinalValue
+    | PercentageValue
+    | InstantTimeValue
+    | TimeIntervalValue
+    | AmountOfMoneyValue
+    | TemperatureValue
+    | DurationValue
+    | MusicAlbumValue
+    | MusicArtistValue
+    | MusicTrackValue
+    | CityValue
+    | CountryValue
+    | RegionValue;
+
+export interface CustomValue {
+    kind: "Custom";
+    value: string;
+}
+
+export interface NumberValue {
+    kind: "Number";
+    value: number;
+}
+
+export interface OrdinalValue {
+    kind: "Ordinal";
+    value: number;
+}
+
+export interface PercentageValue {
+    kind: "Percentage";
+    value: number;
+}
+
+export interface InstantTimeValue {
+    kind: "InstantTime";
+    value: string;
+    grain: Grain;
+    precision: Precision;
+}
+
+export interface TimeIntervalValue {
+    kind: "TimeInterval";
+    from: string | null;
+    to: string | null;
+}
+
+export interface AmountOfMoneyValue {
+    kind: "AmountOfMoney";
+    value: number;
+    precision: Precision;
+    unit: string | null;
+}
+
+export interface TemperatureValue {
+    kind: "Temperature";
+    value: number;
+    unit: string | null;
+}
+
+export interface DurationValue {
+    kind: "Duration";
+    years: number;
+    quarters: number;
+    months: number;
+    weeks: number;
+    days: number;
+    hours: number;
+    minutes: number;
+    seconds: number;
+    precision: Precision;
+}
+
+export interface MusicAlbumValue {
+    kind: "MusicAlbum";
+    value: string;
+}
+
+export interface MusicArtistValue {
+    kind: "MusicArtist";
+    value: string;
+}
+
+export interface MusicTrackValue {
+    kind: "MusicTrack";
+    value: string;
+}
+
+export interface CityValue {
+    kind: "City";
+    value: string;
+}
+
+export interface CountryValue {
+    kind: "Country";
+    value: string;
+}
+
+export interface RegionValue {
+    kind: "Region";
+    value: string;
+}
+
+export type Grain =
+    | "Year"
+    | "Quarter"
+    | "Month"
+    | "Week"
+    | "Day"
+    | "Hour"
+    | "Minute"
+    | "Second";
+
+export type Precision =
+    | "Approximate"
+    | "Exact";
+
+export interface Range {
+    start: number;
+    end: number;
+}
+
+export interface IntentParserAlternative {
+    intent: IntentClassifierResult;
+    slots: Slot[];
+}
@@ -0,0 +1 @@
+pub use snips_nlu_ontology::wasm::*;
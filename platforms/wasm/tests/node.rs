@@ -0,0 +1,36 @@
+//! Tests of the JavaScript bindings, run under Node with `wasm-pack test --node platforms/wasm`
+#![cfg(target_arch = "wasm32")]
+
+use snips_nlu_ontology_wasm::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn parse_intent_parser_result_rejects_invalid_json() {
+    assert!(parse_intent_parser_result(r#"{"input": "hello"}"#).is_err());
+    assert_eq!(1, validate_intent_parser_result("{}").len());
+}
+
+#[wasm_bindgen_test]
+fn pretty_print_intent_parser_result_indents_json() {
+    // Given
+    let json = r#"{"input":"hello","intent":{"intentName":null,"confidenceScore":0.5},"slots":[]}"#;
+
+    // When
+    let pretty_json = pretty_print_intent_parser_result(json).unwrap();
+
+    // Then
+    assert!(pretty_json.contains("\n  \"input\": \"hello\""));
+}
+
+#[wasm_bindgen_test]
+fn builtin_entity_kind_lookups() {
+    // When
+    let kind = WasmBuiltinEntityKind::from_identifier("snips/number").unwrap();
+
+    // Then
+    assert_eq!("Number", kind.name());
+    assert!(kind.result_description().contains("\"kind\": \"Number\""));
+    assert!(kind.description_in("xx").is_err());
+    assert!(WasmBuiltinEntityKind::from_identifier("snips/unknown").is_err());
+    assert!(languages().contains(&"en".to_string()));
+}
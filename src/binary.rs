@@ -0,0 +1,193 @@
+//! Compact binary encoding of the ontology types, based on MessagePack
+//!
+//! An encoded value is made of a single byte holding `BINARY_FORMAT_VERSION`, followed by the
+//! MessagePack payload. Structs are encoded as maps with their serde field names, so that the
+//! internally tagged `SlotValue` representation round-trips exactly, as it does in JSON.
+
+use crate::entity::builtin_entity::{BuiltinEntity, LenientBuiltinEntity};
+use crate::errors::*;
+use crate::lenient::Lenient;
+use crate::ontology::*;
+use failure::{bail, format_err};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Version of the binary envelope, to be incremented whenever the encoding changes in a way
+/// which older versions of the library cannot decode
+pub const BINARY_FORMAT_VERSION: u8 = 1;
+
+/// Types which can be encoded in the compact binary format
+pub trait BinaryEncoding: Serialize + DeserializeOwned {
+    fn to_binary(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![BINARY_FORMAT_VERSION];
+        rmp_serde::encode::write_named(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    fn from_binary(bytes: &[u8]) -> Result<Self> {
+        Ok(rmp_serde::from_slice(payload(bytes)?)?)
+    }
+
+    /// Decodes a value which may have been encoded by a more recent version of the ontology,
+    /// keeping the slot values and entity kinds it does not know, see `lenient::Lenient`
+    fn from_binary_lenient(bytes: &[u8]) -> Result<Self>
+    where
+        Lenient<Self>: DeserializeOwned,
+    {
+        Ok(rmp_serde::from_slice::<Lenient<Self>>(payload(bytes)?)?.into_inner())
+    }
+}
+
+/// Checks the version of an encoded value and returns its MessagePack payload
+fn payload(bytes: &[u8]) -> Result<&[u8]> {
+    let (version, payload) = bytes
+        .split_first()
+        .ok_or_else(|| format_err!("Cannot decode an empty binary payload"))?;
+    if *version != BINARY_FORMAT_VERSION {
+        bail!(
+            "Unsupported binary format version {}, expected version {}",
+            version,
+            BINARY_FORMAT_VERSION
+        );
+    }
+    Ok(payload)
+}
+
+impl BinaryEncoding for IntentParserResult {}
+
+impl BinaryEncoding for BuiltinEntity {}
+
+impl BinaryEncoding for LenientBuiltinEntity {}
+
+impl BinaryEncoding for SlotValue {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::BuiltinEntityKind;
+
+    fn intent_parser_result() -> IntentParserResult {
+        let slot = Slot {
+            raw_value: "21 degrees".to_string(),
+            value: SlotValue::Temperature(TemperatureValue {
+                value: 21.0,
+                unit: Some("celsius".to_string()),
+            }),
+            alternatives: vec![SlotValue::Temperature(TemperatureValue {
+                value: 21.0,
+                unit: None,
+            })],
+            range: 17..27,
+            entity: "snips/temperature".to_string(),
+            slot_name: "temperature".to_string(),
+            confidence_score: Some(0.9),
+        };
+        IntentParserResult {
+            input: "turn the heat to 21 degrees".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("SetTemperature".to_string()),
+                confidence_score: 0.8,
+            },
+            slots: vec![slot.clone()],
+            alternatives: vec![IntentParserAlternative {
+                intent: IntentClassifierResult {
+                    intent_name: None,
+                    confidence_score: 0.1,
+                },
+                slots: vec![Slot {
+                    confidence_score: None,
+                    ..slot
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_intent_parser_result_round_trip() {
+        // Given
+        let result = intent_parser_result();
+
+        // When
+        let bytes = result.to_binary().unwrap();
+
+        // Then
+        let json_bytes = serde_json::to_vec(&result).unwrap();
+        assert_eq!(BINARY_FORMAT_VERSION, bytes[0]);
+        assert!(bytes.len() < json_bytes.len());
+        assert_eq!(result, IntentParserResult::from_binary(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_slot_values_and_builtin_entities_round_trip() {
+        for kind in BuiltinEntityKind::all() {
+            // Given
+            let values: Vec<SlotValue> = serde_json::from_str(&kind.result_description()).unwrap();
+            let entity = BuiltinEntity {
+                value: "foo".to_string(),
+                range: 0..3,
+                entity: values[0].clone(),
+                alternatives: values[1..].to_vec(),
+                entity_kind: *kind,
+            };
+
+            // When
+            let entity_bytes = entity.to_binary().unwrap();
+
+            // Then
+            assert_eq!(entity, BuiltinEntity::from_binary(&entity_bytes).unwrap());
+            for value in values {
+                let bytes = value.to_binary().unwrap();
+                assert_eq!(value, SlotValue::from_binary(&bytes).unwrap());
+            }
+        }
+        let custom_value = SlotValue::Custom("foo".into());
+        let bytes = custom_value.to_binary().unwrap();
+        assert_eq!(custom_value, SlotValue::from_binary(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_slot_values_round_trip_leniently() {
+        // Given
+        let mut result = intent_parser_result();
+        result.slots[0].value =
+            crate::lenient::from_str(r#"{"kind": "Fortnight", "count": 2}"#).unwrap();
+        let entity: LenientBuiltinEntity = crate::lenient::from_str(
+            r#"{
+                "value": "a fortnight",
+                "range": {"start": 0, "end": 11},
+                "entity": {"kind": "Fortnight", "count": 1},
+                "alternatives": [],
+                "entity_kind": "snips/fortnight"
+            }"#,
+        )
+        .unwrap();
+
+        // When
+        let bytes = result.to_binary().unwrap();
+        let entity_bytes = entity.to_binary().unwrap();
+
+        // Then
+        assert!(IntentParserResult::from_binary(&bytes).is_err());
+        assert_eq!(
+            result,
+            IntentParserResult::from_binary_lenient(&bytes).unwrap()
+        );
+        assert_eq!(
+            entity,
+            LenientBuiltinEntity::from_binary_lenient(&entity_bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unsupported_payloads_are_rejected() {
+        // Given
+        let mut bytes = intent_parser_result().to_binary().unwrap();
+        bytes[0] = BINARY_FORMAT_VERSION + 1;
+
+        // When/Then
+        assert!(IntentParserResult::from_binary(&bytes).is_err());
+        assert!(IntentParserResult::from_binary(&[]).is_err());
+        assert!(IntentParserResult::from_binary(&[BINARY_FORMAT_VERSION, 0xc1]).is_err());
+        assert!(IntentParserResult::from_binary_lenient(&bytes).is_err());
+    }
+}
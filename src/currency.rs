@@ -0,0 +1,399 @@
+//! Normalization of the free-form `unit` of `AmountOfMoneyValue` into ISO-4217 currencies
+
+use crate::errors::*;
+use crate::ontology::*;
+use failure::{bail, format_err};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum Currency {
+    AUD,
+    BRL,
+    CAD,
+    CHF,
+    CNY,
+    DKK,
+    EUR,
+    GBP,
+    HKD,
+    INR,
+    JPY,
+    KRW,
+    MXN,
+    NOK,
+    NZD,
+    RUB,
+    SEK,
+    SGD,
+    THB,
+    USD,
+}
+
+const DOLLARS: &[Currency] = &[
+    Currency::USD,
+    Currency::CAD,
+    Currency::AUD,
+    Currency::NZD,
+    Currency::HKD,
+    Currency::SGD,
+    Currency::MXN,
+];
+const YENS: &[Currency] = &[Currency::JPY, Currency::CNY];
+const CROWNS: &[Currency] = &[Currency::SEK, Currency::NOK, Currency::DKK];
+
+impl Currency {
+    pub fn all() -> &'static [Currency] {
+        static ALL: &[Currency] = &[
+            Currency::AUD,
+            Currency::BRL,
+            Currency::CAD,
+            Currency::CHF,
+            Currency::CNY,
+            Currency::DKK,
+            Currency::EUR,
+            Currency::GBP,
+            Currency::HKD,
+            Currency::INR,
+            Currency::JPY,
+            Currency::KRW,
+            Currency::MXN,
+            Currency::NOK,
+            Currency::NZD,
+            Currency::RUB,
+            Currency::SEK,
+            Currency::SGD,
+            Currency::THB,
+            Currency::USD,
+        ];
+        ALL
+    }
+
+    /// Returns the ISO-4217 alphabetic code of the currency
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Currency::AUD => "AUD",
+            Currency::BRL => "BRL",
+            Currency::CAD => "CAD",
+            Currency::CHF => "CHF",
+            Currency::CNY => "CNY",
+            Currency::DKK => "DKK",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::HKD => "HKD",
+            Currency::INR => "INR",
+            Currency::JPY => "JPY",
+            Currency::KRW => "KRW",
+            Currency::MXN => "MXN",
+            Currency::NOK => "NOK",
+            Currency::NZD => "NZD",
+            Currency::RUB => "RUB",
+            Currency::SEK => "SEK",
+            Currency::SGD => "SGD",
+            Currency::THB => "THB",
+            Currency::USD => "USD",
+        }
+    }
+
+    /// Returns the number of digits of the minor unit of the currency, as defined by ISO-4217
+    pub fn minor_unit_digits(&self) -> u32 {
+        match *self {
+            Currency::JPY | Currency::KRW => 0,
+            _ => 2,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Currency> {
+        Currency::all()
+            .iter()
+            .find(|c| c.code().eq_ignore_ascii_case(code))
+            .cloned()
+    }
+
+    /// Returns the currencies which may be designated by `unit`, which can be an ISO-4217
+    /// code, a symbol or an english name
+    ///
+    /// Several currencies are returned when the unit is ambiguous, as is the case for "$".
+    pub fn candidates(unit: &str) -> &'static [Currency] {
+        let unit = unit.trim();
+        if let Some(currency) = Currency::all()
+            .iter()
+            .find(|c| c.code().eq_ignore_ascii_case(unit))
+        {
+            return ::std::slice::from_ref(currency);
+        }
+        match &*unit.to_lowercase() {
+            "$" | "dollar" | "dollars" => DOLLARS,
+            "us$" => &[Currency::USD],
+            "c$" | "ca$" => &[Currency::CAD],
+            "a$" | "au$" => &[Currency::AUD],
+            "nz$" => &[Currency::NZD],
+            "hk$" => &[Currency::HKD],
+            "s$" => &[Currency::SGD],
+            "r$" | "real" | "reais" => &[Currency::BRL],
+            "€" | "euro" | "euros" => &[Currency::EUR],
+            "£" | "pound" | "pounds" => &[Currency::GBP],
+            "¥" | "￥" => YENS,
+            "円" | "yen" => &[Currency::JPY],
+            "元" | "yuan" | "renminbi" => &[Currency::CNY],
+            "₩" | "원" | "won" => &[Currency::KRW],
+            "₹" | "rupee" | "rupees" => &[Currency::INR],
+            "₽" | "rouble" | "roubles" | "ruble" | "rubles" => &[Currency::RUB],
+            "฿" | "baht" => &[Currency::THB],
+            "kr" | "crown" | "crowns" => CROWNS,
+            "chf" | "franc" | "francs" => &[Currency::CHF],
+            _ => &[],
+        }
+    }
+
+    /// Resolves `unit` to a single currency
+    ///
+    /// When the unit is ambiguous, the first currency of `preferred` which is a candidate is
+    /// returned, for instance `&[Currency::CAD]` resolves "$" to canadian dollars.
+    pub fn resolve(unit: &str, preferred: &[Currency]) -> Result<Currency> {
+        let candidates = Currency::candidates(unit);
+        match candidates {
+            [] => bail!("Unknown currency: '{}'", unit),
+            [currency] => Ok(*currency),
+            _ => preferred
+                .iter()
+                .find(|c| candidates.contains(c))
+                .cloned()
+                .ok_or_else(|| {
+                    format_err!(
+                        "Ambiguous currency '{}', which may be any of {:?}",
+                        unit,
+                        candidates
+                    )
+                }),
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Exact amount of money, expressed as an integer number of minor units of its currency
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+pub struct Money {
+    pub currency: Currency,
+    pub minor_units: i64,
+}
+
+impl Money {
+    /// Builds an amount from its decimal representation, such as "1999.99"
+    ///
+    /// Decimals beyond the minor unit of the currency are rounded half away from zero.
+    pub fn from_decimal_str(value: &str, currency: Currency) -> Result<Money> {
+        let invalid = || format_err!("Invalid decimal amount: '{}'", value);
+        let (is_negative, unsigned) = match value.strip_prefix('-') {
+            Some(stripped) => (true, stripped),
+            None => (false, value),
+        };
+        let mut parts = unsigned.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let digits = currency.minor_unit_digits() as usize;
+        // The sign is parsed along with the digits so that `i64::MIN` minor units can be reached
+        let mut minor_digits: String = if is_negative { "-0" } else { "0" }.to_string();
+        minor_digits.push_str(integer);
+        minor_digits.extend(
+            fraction
+                .chars()
+                .chain(::std::iter::repeat('0'))
+                .take(digits),
+        );
+        let mut minor_units = minor_digits.parse::<i64>().map_err(|_| invalid())?;
+        if let Some('5'..='9') = fraction.chars().nth(digits) {
+            let rounded = if is_negative {
+                minor_units.checked_sub(1)
+            } else {
+                minor_units.checked_add(1)
+            };
+            minor_units = rounded.ok_or_else(invalid)?;
+        }
+        Ok(Money {
+            currency,
+            minor_units,
+        })
+    }
+
+    /// Returns the decimal representation of the amount, with as many decimals as the minor
+    /// unit of the currency
+    pub fn to_decimal_string(&self) -> String {
+        let digits = self.currency.minor_unit_digits();
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let absolute = self.minor_units.unsigned_abs();
+        if digits == 0 {
+            return format!("{}{}", sign, absolute);
+        }
+        let factor = 10_u64.pow(digits);
+        format!(
+            "{}{}.{:0width$}",
+            sign,
+            absolute / factor,
+            absolute % factor,
+            width = digits as usize
+        )
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+impl AmountOfMoneyValue {
+    /// Resolves the unit of the amount to a currency, see `Currency::resolve`
+    pub fn currency(&self, preferred: &[Currency]) -> Result<Currency> {
+        let unit = self
+            .unit
+            .as_ref()
+            .ok_or_else(|| format_err!("Missing currency in {:?}", self))?;
+        Currency::resolve(unit, preferred)
+    }
+
+    /// Converts the amount to an exact `Money` value
+    ///
+    /// The amount is rounded to the minor unit of the currency based on the shortest decimal
+    /// representation of `value`, so that 1999.99 is not turned into 1999.98.
+    ///
+    /// As `value` is an `f32`, the result is only exact for amounts of at most 7 significant
+    /// digits: 99999.99 is converted exactly whereas 1234567.89 becomes 1234567.90.
+    pub fn to_money(&self, preferred: &[Currency]) -> Result<Money> {
+        Money::from_decimal_str(&self.value.to_string(), self.currency(preferred)?)
+    }
+
+    /// Builds an amount whose unit is the ISO-4217 code of the currency
+    pub fn from_money(money: &Money, precision: Precision) -> Self {
+        AmountOfMoneyValue {
+            value: money.to_decimal_string().parse().unwrap(),
+            precision,
+            unit: Some(money.currency.code().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_candidates() {
+        assert_eq!(&[Currency::EUR], Currency::candidates("€"));
+        assert_eq!(&[Currency::EUR], Currency::candidates("eur"));
+        assert_eq!(&[Currency::GBP], Currency::candidates("Pounds"));
+        assert_eq!(&[Currency::CAD], Currency::candidates("C$"));
+        assert!(Currency::candidates("$").contains(&Currency::USD));
+        assert!(Currency::candidates("$").contains(&Currency::AUD));
+        assert!(Currency::candidates("cent").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_ambiguous_currency() {
+        assert_eq!(
+            Currency::CAD,
+            Currency::resolve("$", &[Currency::EUR, Currency::CAD]).unwrap()
+        );
+        assert_eq!(
+            Currency::EUR,
+            Currency::resolve("€", &[Currency::CAD]).unwrap()
+        );
+        assert!(Currency::resolve("$", &[]).is_err());
+        assert!(Currency::resolve("foo", &[Currency::USD]).is_err());
+    }
+
+    #[test]
+    fn test_to_money() {
+        // Given
+        let amount = AmountOfMoneyValue {
+            value: 1999.99,
+            precision: Precision::Exact,
+            unit: Some("$".to_string()),
+        };
+
+        // When
+        let money = amount.to_money(&[Currency::USD]).unwrap();
+
+        // Then
+        let expected_money = Money {
+            currency: Currency::USD,
+            minor_units: 199_999,
+        };
+        assert_eq!(expected_money, money);
+        assert_eq!("1999.99 USD", money.to_string());
+    }
+
+    #[test]
+    fn test_money_from_decimal_str() {
+        let money = |value: &str, currency: Currency| {
+            Money::from_decimal_str(value, currency)
+                .unwrap()
+                .minor_units
+        };
+        assert_eq!(1005, money("10.05", Currency::EUR));
+        assert_eq!(1010, money("10.1", Currency::EUR));
+        assert_eq!(1001, money("10.005", Currency::EUR));
+        assert_eq!(-50, money("-.5", Currency::EUR));
+        assert_eq!(1000, money("999.5", Currency::JPY));
+        assert!(Money::from_decimal_str("1e3", Currency::EUR).is_err());
+        assert!(Money::from_decimal_str(".", Currency::EUR).is_err());
+        assert!(Money::from_decimal_str("92233720368547758.075", Currency::EUR).is_err());
+    }
+
+    #[test]
+    fn test_to_money_is_exact_within_f32_precision() {
+        let to_money = |value: f32| {
+            let amount = AmountOfMoneyValue {
+                value,
+                precision: Precision::Exact,
+                unit: Some("EUR".to_string()),
+            };
+            amount.to_money(&[]).unwrap().minor_units
+        };
+        assert_eq!(9_999_999, to_money(99999.99));
+        assert_eq!(123_456_790, to_money("1234567.89".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_extreme_amounts_to_decimal_string() {
+        let money = |minor_units: i64| Money {
+            currency: Currency::EUR,
+            minor_units,
+        };
+        assert_eq!("-92233720368547758.08", money(i64::MIN).to_decimal_string());
+        assert_eq!("92233720368547758.07", money(i64::MAX).to_decimal_string());
+        assert_eq!(
+            money(i64::MIN),
+            Money::from_decimal_str("-92233720368547758.08", Currency::EUR).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_money_round_trip() {
+        // Given
+        let money = Money {
+            currency: Currency::KRW,
+            minor_units: 5000,
+        };
+
+        // When
+        let amount = AmountOfMoneyValue::from_money(&money, Precision::Approximate);
+
+        // Then
+        assert_eq!(Some("KRW".to_string()), amount.unit);
+        assert_eq!(money, amount.to_money(&[]).unwrap());
+    }
+}
@@ -0,0 +1,404 @@
+//! Types of the Snips NLU training dataset
+//!
+//! A dataset is made of a language, of intents whose utterances are sequences of text and slot
+//! chunks, and of the entities referenced by these slots, builtin entities being declared with
+//! an empty object under their identifier.
+
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::entity::custom_entity::CustomEntityDescriptor;
+use crate::entity::entity_ref::EntityRef;
+use crate::language::Language;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{self, SerializeMap, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Dataset {
+    #[serde(
+        serialize_with = "serialize_language",
+        deserialize_with = "deserialize_language"
+    )]
+    pub language: Language,
+    pub intents: BTreeMap<String, Intent>,
+    #[serde(
+        serialize_with = "serialize_entities",
+        deserialize_with = "deserialize_entities"
+    )]
+    pub entities: Vec<DatasetEntity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Intent {
+    pub utterances: Vec<Utterance>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Utterance {
+    pub data: Vec<Chunk>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Chunk {
+    Slot(SlotChunk),
+    Text(TextChunk),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TextChunk {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SlotChunk {
+    pub text: String,
+    /// Identifier of the builtin entity, or name of the custom entity, of the slot
+    pub entity: String,
+    pub slot_name: String,
+}
+
+/// Entity declared in a dataset
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatasetEntity {
+    Builtin(BuiltinEntityKind),
+    Custom(CustomEntityDescriptor),
+}
+
+impl DatasetEntity {
+    pub fn entity_ref(&self) -> EntityRef {
+        match self {
+            DatasetEntity::Builtin(kind) => EntityRef::Builtin(*kind),
+            DatasetEntity::Custom(entity) => EntityRef::from(entity),
+        }
+    }
+}
+
+/// Serialized form of a builtin entity
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BuiltinEntityData {}
+
+fn serialize_language<S: Serializer>(
+    language: &Language,
+    serializer: S,
+) -> ::std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&language.to_string())
+}
+
+fn deserialize_language<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> ::std::result::Result<Language, D::Error> {
+    String::deserialize(deserializer)
+        .and_then(|s| Language::from_str(&s).map_err(de::Error::custom))
+}
+
+fn serialize_entities<S: Serializer>(
+    entities: &[DatasetEntity],
+    serializer: S,
+) -> ::std::result::Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(entities.len()))?;
+    for entity in entities {
+        match entity {
+            DatasetEntity::Builtin(kind) => {
+                map.serialize_entry(kind.identifier(), &BuiltinEntityData {})?
+            }
+            DatasetEntity::Custom(entity) => {
+                // The name is the key of the entity, hence it is not repeated in its value
+                let mut value = serde_json::to_value(entity).map_err(ser::Error::custom)?;
+                if let Some(fields) = value.as_object_mut() {
+                    fields.remove("name");
+                }
+                map.serialize_entry(&entity.name, &value)?
+            }
+        }
+    }
+    map.end()
+}
+
+fn deserialize_entities<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> ::std::result::Result<Vec<DatasetEntity>, D::Error> {
+    BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(identifier, value)| {
+            let entity = match EntityRef::from_identifier(&identifier) {
+                Ok(EntityRef::Builtin(kind)) => {
+                    BuiltinEntityData::deserialize(value).map(|_| DatasetEntity::Builtin(kind))
+                }
+                Ok(EntityRef::Custom(name)) => CustomEntityDescriptor::deserialize(value)
+                    .map(|entity| DatasetEntity::Custom(CustomEntityDescriptor { name, ..entity })),
+                Err(error) => return Err(de::Error::custom(error)),
+            };
+            entity.map_err(|e| de::Error::custom(format!("entity '{}': {}", identifier, e)))
+        })
+        .collect()
+}
+
+/// Inconsistency found when validating a `Dataset`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatasetIssue {
+    /// Name of the intent in which the issue was found, if any
+    pub intent: Option<String>,
+    /// Index of the utterance in which the issue was found, if any
+    pub utterance_index: Option<usize>,
+    pub kind: DatasetIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatasetIssueKind {
+    /// The entity of a slot chunk is not declared in the dataset entities
+    UndeclaredEntity { entity: String, slot_name: String },
+    /// The slot is mapped to different entities within the same intent
+    InconsistentSlotEntity {
+        slot_name: String,
+        entity: String,
+        other_entity: String,
+    },
+    /// The builtin entity is not supported in the language of the dataset
+    UnsupportedBuiltinEntity(BuiltinEntityKind),
+    /// The matching strictness of the custom entity is not in [0, 1]
+    InvalidMatchingStrictness { entity: String, strictness: f32 },
+}
+
+impl fmt::Display for DatasetIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.intent, self.utterance_index) {
+            (Some(intent), Some(index)) => write!(f, "intent {}, utterance {}: ", intent, index)?,
+            (Some(intent), None) => write!(f, "intent {}: ", intent)?,
+            (None, _) => write!(f, "entities: ")?,
+        }
+        match &self.kind {
+            DatasetIssueKind::UndeclaredEntity { entity, slot_name } => write!(
+                f,
+                "entity '{}' of slot '{}' is not declared",
+                entity, slot_name
+            ),
+            DatasetIssueKind::InconsistentSlotEntity {
+                slot_name,
+                entity,
+                other_entity,
+            } => write!(
+                f,
+                "slot '{}' refers to both entity '{}' and entity '{}'",
+                slot_name, entity, other_entity
+            ),
+            DatasetIssueKind::UnsupportedBuiltinEntity(kind) => {
+                write!(f, "{} is not supported in this language", kind.identifier())
+            }
+            DatasetIssueKind::InvalidMatchingStrictness { entity, strictness } => write!(
+                f,
+                "matching strictness {} of entity '{}' is not in [0, 1]",
+                strictness, entity
+            ),
+        }
+    }
+}
+
+impl Dataset {
+    /// Checks that slots refer to declared entities consistently, and that entities are valid in
+    /// the dataset language, returning the issues found, if any
+    pub fn validate(&self) -> Vec<DatasetIssue> {
+        let mut issues = vec![];
+        for entity in &self.entities {
+            let kind = match entity {
+                DatasetEntity::Builtin(kind)
+                    if !kind.supported_languages().contains(&self.language) =>
+                {
+                    DatasetIssueKind::UnsupportedBuiltinEntity(*kind)
+                }
+                DatasetEntity::Custom(entity)
+                    if !(0.0..=1.0).contains(&entity.matching_strictness) =>
+                {
+                    DatasetIssueKind::InvalidMatchingStrictness {
+                        entity: entity.name.clone(),
+                        strictness: entity.matching_strictness,
+                    }
+                }
+                _ => continue,
+            };
+            issues.push(DatasetIssue {
+                intent: None,
+                utterance_index: None,
+                kind,
+            });
+        }
+
+        let declared_entities: Vec<EntityRef> = self
+            .entities
+            .iter()
+            .map(DatasetEntity::entity_ref)
+            .collect();
+        for (intent_name, intent) in &self.intents {
+            let mut slot_entities: HashMap<&str, &str> = HashMap::new();
+            for (utterance_index, utterance) in intent.utterances.iter().enumerate() {
+                for chunk in &utterance.data {
+                    let chunk = match chunk {
+                        Chunk::Slot(chunk) => chunk,
+                        Chunk::Text(_) => continue,
+                    };
+                    let is_declared = EntityRef::from_identifier(&chunk.entity)
+                        .map(|entity| declared_entities.contains(&entity))
+                        .unwrap_or(false);
+                    let slot_entity = *slot_entities
+                        .entry(&chunk.slot_name)
+                        .or_insert(&chunk.entity);
+                    let kind = if !is_declared {
+                        DatasetIssueKind::UndeclaredEntity {
+                            entity: chunk.entity.clone(),
+                            slot_name: chunk.slot_name.clone(),
+                        }
+                    } else if slot_entity != chunk.entity {
+                        DatasetIssueKind::InconsistentSlotEntity {
+                            slot_name: chunk.slot_name.clone(),
+                            entity: slot_entity.to_string(),
+                            other_entity: chunk.entity.clone(),
+                        }
+                    } else {
+                        continue;
+                    };
+                    issues.push(DatasetIssue {
+                        intent: Some(intent_name.clone()),
+                        utterance_index: Some(utterance_index),
+                        kind,
+                    });
+                }
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::custom_entity::EntityValue;
+
+    const DATASET: &str = r#"{
+        "language": "en",
+        "intents": {
+            "orderDrink": {
+                "utterances": [
+                    {"data": [
+                        {"text": "I want "},
+                        {"text": "two", "entity": "snips/number", "slot_name": "count"},
+                        {"text": " cups of "},
+                        {"text": "coffee", "entity": "beverage", "slot_name": "drink"}
+                    ]},
+                    {"data": [{"text": "order something"}]}
+                ]
+            }
+        },
+        "entities": {
+            "beverage": {
+                "data": [{"value": "coffee", "synonyms": ["espresso"]}],
+                "use_synonyms": true,
+                "automatically_extensible": false,
+                "matching_strictness": 0.8
+            },
+            "snips/number": {}
+        }
+    }"#;
+
+    #[test]
+    fn test_dataset_round_trip() {
+        // When
+        let dataset: Dataset = serde_json::from_str(DATASET).unwrap();
+
+        // Then
+        let expected_entities = vec![
+            DatasetEntity::Custom(CustomEntityDescriptor {
+                automatically_extensible: false,
+                matching_strictness: 0.8,
+                values: vec![EntityValue::new("coffee", vec!["espresso".to_string()])],
+                ..CustomEntityDescriptor::new("beverage")
+            }),
+            DatasetEntity::Builtin(BuiltinEntityKind::Number),
+        ];
+        let utterances = &dataset.intents["orderDrink"].utterances;
+        assert_eq!(Language::EN, dataset.language);
+        assert_eq!(expected_entities, dataset.entities);
+        assert_eq!(4, utterances[0].data.len());
+        assert_eq!(
+            Chunk::Text(TextChunk {
+                text: "order something".to_string()
+            }),
+            utterances[1].data[0]
+        );
+        let json = serde_json::to_value(&dataset).unwrap();
+        assert!(json["entities"]["beverage"].get("name").is_none());
+        assert_eq!(dataset, serde_json::from_value(json).unwrap());
+        assert!(dataset.validate().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_datasets_are_rejected() {
+        // Given
+        let unknown_builtin = DATASET.replace("\"snips/number\": {}", "\"snips/unknown\": {}");
+        let builtin_with_data =
+            DATASET.replace("\"snips/number\": {}", "\"snips/number\": {\"data\": []}");
+        let unknown_language = DATASET.replace("\"en\"", "\"xx\"");
+
+        // When/Then
+        assert!(serde_json::from_str::<Dataset>(&unknown_builtin).is_err());
+        assert!(serde_json::from_str::<Dataset>(&builtin_with_data).is_err());
+        assert!(serde_json::from_str::<Dataset>(&unknown_language).is_err());
+    }
+
+    #[test]
+    fn test_validate_dataset() {
+        // Given
+        let mut dataset: Dataset = serde_json::from_str(DATASET).unwrap();
+        dataset.language = Language::KO;
+        dataset
+            .entities
+            .push(DatasetEntity::Builtin(BuiltinEntityKind::City));
+        dataset
+            .entities
+            .push(DatasetEntity::Custom(CustomEntityDescriptor {
+                matching_strictness: 1.5,
+                ..CustomEntityDescriptor::new("size")
+            }));
+        let utterances = &mut dataset.intents.get_mut("orderDrink").unwrap().utterances;
+        utterances[1].data.push(Chunk::Slot(SlotChunk {
+            text: "tea".to_string(),
+            entity: "snips/city".to_string(),
+            slot_name: "drink".to_string(),
+        }));
+        utterances[1].data.push(Chunk::Slot(SlotChunk {
+            text: "large".to_string(),
+            entity: "cup_size".to_string(),
+            slot_name: "size".to_string(),
+        }));
+
+        // When
+        let issues = dataset.validate();
+
+        // Then
+        let expected_kinds = vec![
+            DatasetIssueKind::UnsupportedBuiltinEntity(BuiltinEntityKind::City),
+            DatasetIssueKind::InvalidMatchingStrictness {
+                entity: "size".to_string(),
+                strictness: 1.5,
+            },
+            DatasetIssueKind::InconsistentSlotEntity {
+                slot_name: "drink".to_string(),
+                entity: "beverage".to_string(),
+                other_entity: "snips/city".to_string(),
+            },
+            DatasetIssueKind::UndeclaredEntity {
+                entity: "cup_size".to_string(),
+                slot_name: "size".to_string(),
+            },
+        ];
+        let kinds: Vec<_> = issues.iter().map(|issue| issue.kind.clone()).collect();
+        assert_eq!(expected_kinds, kinds);
+        assert_eq!(Some(1), issues[3].utterance_index);
+        assert_eq!(
+            "intent orderDrink, utterance 1: entity 'cup_size' of slot 'size' is not declared",
+            issues[3].to_string()
+        );
+    }
+}
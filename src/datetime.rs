@@ -0,0 +1,452 @@
+//! Conversions between the string representation of time values and `chrono` types
+//!
+//! Time values are serialized using the `%Y-%m-%d %H:%M:%S %:z` format, for instance
+//! "2017-06-13 18:00:00 +02:00". Sub-second precision is not part of this format and is thus
+//! dropped when converting from a `chrono` value.
+
+use crate::errors::*;
+use crate::ontology::*;
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+};
+use failure::{format_err, ResultExt};
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::iter;
+
+pub const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S %:z";
+
+pub fn parse_datetime(value: &str) -> Result<DateTime<FixedOffset>> {
+    Ok(DateTime::parse_from_str(value, DATETIME_FORMAT)
+        .with_context(|_| format!("Invalid datetime value: '{}'", value))?)
+}
+
+pub fn format_datetime<Tz>(datetime: &DateTime<Tz>) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    datetime.format(DATETIME_FORMAT).to_string()
+}
+
+impl InstantTimeValue {
+    pub fn from_datetime<Tz>(datetime: &DateTime<Tz>, grain: Grain, precision: Precision) -> Self
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        InstantTimeValue {
+            value: format_datetime(datetime),
+            grain,
+            precision,
+        }
+    }
+
+    pub fn to_datetime(&self) -> Result<DateTime<FixedOffset>> {
+        parse_datetime(&self.value)
+    }
+
+    /// Expands the instant to the interval covered by its grain
+    ///
+    /// For instance, "2017-06-13 00:00:00 +02:00" with a `Grain::Day` becomes the interval
+    /// going from "2017-06-13 00:00:00 +02:00" to "2017-06-14 00:00:00 +02:00".
+    pub fn as_interval(&self) -> Result<TimeIntervalValue> {
+        let from = self.grain.truncate(&self.to_datetime()?);
+        let to = self.grain.next(&from)?;
+        Ok(TimeIntervalValue::from_datetimes(Some(&from), Some(&to)))
+    }
+}
+
+impl TimeIntervalValue {
+    pub fn from_datetimes<Tz>(from: Option<&DateTime<Tz>>, to: Option<&DateTime<Tz>>) -> Self
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        TimeIntervalValue {
+            from: from.map(format_datetime),
+            to: to.map(format_datetime),
+        }
+    }
+
+    /// Returns the bounds of the interval, which are `None` when the interval is open
+    #[allow(clippy::type_complexity)]
+    pub fn to_datetimes(
+        &self,
+    ) -> Result<(Option<DateTime<FixedOffset>>, Option<DateTime<FixedOffset>>)> {
+        let from = self.from.as_ref().map(|v| parse_datetime(v)).transpose()?;
+        let to = self.to.as_ref().map(|v| parse_datetime(v)).transpose()?;
+        Ok((from, to))
+    }
+}
+
+impl SlotValue {
+    /// Checks that the time values, if any, follow the `DATETIME_FORMAT`
+    pub fn validate_datetimes(&self) -> Result<()> {
+        match self {
+            SlotValue::InstantTime(value) => value.to_datetime().map(|_| ()),
+            SlotValue::TimeInterval(value) => value.to_datetimes().map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntentParserResult {
+    /// Checks that the time values of all the slots, including the ones of the alternatives,
+    /// follow the `DATETIME_FORMAT`
+    ///
+    /// Deserialization does not check the format of time values, which are plain strings.
+    pub fn validate_datetimes(&self) -> Result<()> {
+        self.alternatives
+            .iter()
+            .flat_map(|alternative| alternative.slots.iter())
+            .chain(self.slots.iter())
+            .flat_map(|slot| iter::once(&slot.value).chain(slot.alternatives.iter()))
+            .try_for_each(SlotValue::validate_datetimes)
+    }
+}
+
+impl Grain {
+    /// Returns the beginning of the grain period containing `datetime`
+    ///
+    /// Weeks are ISO weeks, starting on mondays, and quarters start in january, april, july
+    /// and october.
+    pub fn truncate(&self, datetime: &DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        let local = datetime.naive_local();
+        let date = local.date();
+        let truncated = match *self {
+            Grain::Year => start_of_day(first_day_of_month(date.year(), 1)),
+            Grain::Quarter => {
+                let first_month_of_quarter = date.month0() / 3 * 3 + 1;
+                start_of_day(first_day_of_month(date.year(), first_month_of_quarter))
+            }
+            Grain::Month => start_of_day(first_day_of_month(date.year(), date.month())),
+            Grain::Week => {
+                let days_from_monday = i64::from(date.weekday().num_days_from_monday());
+                start_of_day(date - Duration::days(days_from_monday))
+            }
+            Grain::Day => start_of_day(date),
+            Grain::Hour => date.and_hms_opt(local.hour(), 0, 0).unwrap(),
+            Grain::Minute => date.and_hms_opt(local.hour(), local.minute(), 0).unwrap(),
+            Grain::Second => date
+                .and_hms_opt(local.hour(), local.minute(), local.second())
+                .unwrap(),
+        };
+        datetime.offset().from_local_datetime(&truncated).unwrap()
+    }
+
+    /// Returns `datetime` shifted by one grain
+    ///
+    /// Shifting by months, quarters or years keeps the day of month when it exists in the
+    /// resulting month, and uses the last day of that month otherwise.
+    pub fn next(&self, datetime: &DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>> {
+        let next = match *self {
+            Grain::Year => datetime.checked_add_months(Months::new(12)),
+            Grain::Quarter => datetime.checked_add_months(Months::new(3)),
+            Grain::Month => datetime.checked_add_months(Months::new(1)),
+            Grain::Week => datetime.checked_add_signed(Duration::weeks(1)),
+            Grain::Day => datetime.checked_add_signed(Duration::days(1)),
+            Grain::Hour => datetime.checked_add_signed(Duration::hours(1)),
+            Grain::Minute => datetime.checked_add_signed(Duration::minutes(1)),
+            Grain::Second => datetime.checked_add_signed(Duration::seconds(1)),
+        };
+        next.ok_or_else(|| format_err!("Cannot shift {} by one {:?}", datetime, self))
+    }
+}
+
+impl DurationValue {
+    /// Returns `reference` shifted by the duration
+    ///
+    /// The years, quarters and months are applied first, following the calendar, and the
+    /// fixed-length components are then added. The grain of `reference` is kept, and the result
+    /// is exact only if both the duration and `reference` are.
+    pub fn add_to(&self, reference: &InstantTimeValue) -> Result<InstantTimeValue> {
+        let start = reference.to_datetime()?;
+        let end = self.shift(&start)?;
+        let precision = match (self.precision, reference.precision) {
+            (Precision::Exact, Precision::Exact) => Precision::Exact,
+            _ => Precision::Approximate,
+        };
+        Ok(InstantTimeValue::from_datetime(
+            &end,
+            reference.grain,
+            precision,
+        ))
+    }
+
+    /// Converts the duration to a `chrono::Duration`, using `reference` as the starting point
+    /// of the calendar-aware components
+    ///
+    /// For instance, one month starting on "2019-02-01" lasts 28 days whereas it lasts 31 days
+    /// when starting on "2019-03-01".
+    pub fn to_chrono_duration(&self, reference: &InstantTimeValue) -> Result<Duration> {
+        let start = reference.to_datetime()?;
+        Ok(self.shift(&start)? - start)
+    }
+
+    fn shift(&self, datetime: &DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>> {
+        let shifted = || -> Option<DateTime<FixedOffset>> {
+            let months = self.calendar_months()?;
+            let calendar_shift = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+            let fixed_shift = Duration::try_seconds(self.fixed_seconds()?)?;
+            if months >= 0 {
+                datetime.checked_add_months(calendar_shift)
+            } else {
+                datetime.checked_sub_months(calendar_shift)
+            }?
+            .checked_add_signed(fixed_shift)
+        };
+        shifted().ok_or_else(|| format_err!("Cannot shift {} by {:?}", datetime, self))
+    }
+}
+
+impl From<Duration> for DurationValue {
+    /// Sub-second precision is dropped
+    fn from(duration: Duration) -> Self {
+        DurationValue::from_seconds(duration.num_seconds())
+    }
+}
+
+fn first_day_of_month(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+}
+
+fn start_of_day(date: NaiveDate) -> NaiveDateTime {
+    date.and_hms_opt(0, 0, 0).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_instant_time_value_round_trip() {
+        // Given
+        let value = InstantTimeValue {
+            value: "2017-06-13 18:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let datetime = value.to_datetime().unwrap();
+        let round_tripped =
+            InstantTimeValue::from_datetime(&datetime, Grain::Hour, Precision::Exact);
+
+        // Then
+        assert_eq!(18, datetime.hour());
+        assert_eq!(2 * 3600, datetime.offset().local_minus_utc());
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn test_instant_time_value_from_utc_datetime() {
+        // Given
+        let datetime = Utc.with_ymd_and_hms(2019, 9, 6, 8, 30, 0).unwrap();
+
+        // When
+        let value = InstantTimeValue::from_datetime(&datetime, Grain::Minute, Precision::Exact);
+
+        // Then
+        assert_eq!("2019-09-06 08:30:00 +00:00", value.value);
+    }
+
+    #[test]
+    fn test_time_interval_value_round_trip() {
+        // Given
+        let value = TimeIntervalValue {
+            from: Some("2017-06-07 18:00:00 +02:00".to_string()),
+            to: None,
+        };
+
+        // When
+        let (from, to) = value.to_datetimes().unwrap();
+        let round_tripped = TimeIntervalValue::from_datetimes(from.as_ref(), to.as_ref());
+
+        // Then
+        assert!(to.is_none());
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn test_as_interval_with_day_grain() {
+        // Given
+        let value = InstantTimeValue {
+            value: "2017-06-13 00:00:00 +02:00".to_string(),
+            grain: Grain::Day,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let interval = value.as_interval().unwrap();
+
+        // Then
+        let expected_interval = TimeIntervalValue {
+            from: Some("2017-06-13 00:00:00 +02:00".to_string()),
+            to: Some("2017-06-14 00:00:00 +02:00".to_string()),
+        };
+        assert_eq!(expected_interval, interval);
+    }
+
+    #[test]
+    fn test_as_interval_with_week_grain() {
+        // Given
+        let value = InstantTimeValue {
+            value: "2019-09-06 00:00:00 +02:00".to_string(),
+            grain: Grain::Week,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let interval = value.as_interval().unwrap();
+
+        // Then
+        let expected_interval = TimeIntervalValue {
+            from: Some("2019-09-02 00:00:00 +02:00".to_string()),
+            to: Some("2019-09-09 00:00:00 +02:00".to_string()),
+        };
+        assert_eq!(expected_interval, interval);
+    }
+
+    #[test]
+    fn test_grain_truncate() {
+        // Given
+        let datetime = parse_datetime("2019-08-31 18:42:13 -05:00").unwrap();
+
+        // When/Then
+        let truncate = |grain: Grain| format_datetime(&grain.truncate(&datetime));
+        assert_eq!("2019-01-01 00:00:00 -05:00", truncate(Grain::Year));
+        assert_eq!("2019-07-01 00:00:00 -05:00", truncate(Grain::Quarter));
+        assert_eq!("2019-08-01 00:00:00 -05:00", truncate(Grain::Month));
+        assert_eq!("2019-08-26 00:00:00 -05:00", truncate(Grain::Week));
+        assert_eq!("2019-08-31 00:00:00 -05:00", truncate(Grain::Day));
+        assert_eq!("2019-08-31 18:00:00 -05:00", truncate(Grain::Hour));
+        assert_eq!("2019-08-31 18:42:00 -05:00", truncate(Grain::Minute));
+        assert_eq!("2019-08-31 18:42:13 -05:00", truncate(Grain::Second));
+    }
+
+    #[test]
+    fn test_grain_next() {
+        // Given
+        let datetime = parse_datetime("2019-12-31 23:59:59 +09:00").unwrap();
+
+        // When/Then
+        let next = |grain: Grain| format_datetime(&grain.next(&datetime).unwrap());
+        assert_eq!("2020-12-31 23:59:59 +09:00", next(Grain::Year));
+        assert_eq!("2020-03-31 23:59:59 +09:00", next(Grain::Quarter));
+        assert_eq!("2020-01-31 23:59:59 +09:00", next(Grain::Month));
+        assert_eq!("2020-01-07 23:59:59 +09:00", next(Grain::Week));
+        assert_eq!("2020-01-01 23:59:59 +09:00", next(Grain::Day));
+        assert_eq!("2020-01-01 00:59:59 +09:00", next(Grain::Hour));
+        assert_eq!("2020-01-01 00:00:59 +09:00", next(Grain::Minute));
+        assert_eq!("2020-01-01 00:00:00 +09:00", next(Grain::Second));
+    }
+
+    #[test]
+    fn test_duration_add_to_is_calendar_aware() {
+        // Given
+        let one_month = DurationValue::from_iso8601_str("P1M").unwrap();
+        let february = InstantTimeValue {
+            value: "2019-02-01 00:00:00 +01:00".to_string(),
+            grain: Grain::Day,
+            precision: Precision::Exact,
+        };
+        let march = InstantTimeValue {
+            value: "2019-03-01 00:00:00 +01:00".to_string(),
+            grain: Grain::Day,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let end_of_february = one_month.add_to(&february).unwrap();
+        let february_duration = one_month.to_chrono_duration(&february).unwrap();
+        let march_duration = one_month.to_chrono_duration(&march).unwrap();
+
+        // Then
+        assert_eq!(march, end_of_february);
+        assert_eq!(Duration::days(28), february_duration);
+        assert_eq!(Duration::days(31), march_duration);
+    }
+
+    #[test]
+    fn test_negative_duration_add_to() {
+        // Given
+        let duration = -DurationValue::from_iso8601_str("P1Y1DT2H").unwrap();
+        let reference = InstantTimeValue {
+            value: "2019-09-06 10:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Approximate,
+        };
+
+        // When
+        let shifted = duration.add_to(&reference).unwrap();
+
+        // Then
+        let expected_shifted = InstantTimeValue {
+            value: "2018-09-05 08:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Approximate,
+        };
+        assert_eq!(expected_shifted, shifted);
+    }
+
+    #[test]
+    fn test_overflowing_duration_add_to_is_rejected() {
+        // Given
+        let reference = InstantTimeValue {
+            value: "2019-09-06 10:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Exact,
+        };
+        let durations = vec![
+            DurationValue::from_iso8601_str("P5000000000M").unwrap(),
+            DurationValue::from_iso8601_str("PT9223372036854775807S").unwrap(),
+        ];
+
+        // When/Then
+        for duration in durations {
+            assert!(duration.add_to(&reference).is_err(), "{:?}", duration);
+        }
+    }
+
+    #[test]
+    fn test_duration_from_chrono_duration() {
+        assert_eq!(
+            DurationValue::from_iso8601_str("P1DT1H30M").unwrap(),
+            DurationValue::from(Duration::minutes(1530))
+        );
+    }
+
+    #[test]
+    fn test_validate_datetimes() {
+        // Given
+        let json = r#"{
+            "input": "remind me tomorrow",
+            "intent": {"intentName": "setReminder", "confidenceScore": 0.5},
+            "slots": [{
+                "rawValue": "tomorrow",
+                "value": {"kind": "TimeInterval", "from": "2017-06-07 18:00:00 +02:00"},
+                "alternatives": [{"kind": "InstantTime", "value": "tomorrow",
+                    "grain": "Day", "precision": "Exact"}],
+                "range": {"start": 10, "end": 18},
+                "entity": "snips/datetime",
+                "slotName": "date"
+            }],
+            "alternatives": []
+        }"#;
+
+        // When
+        let mut result: IntentParserResult = serde_json::from_str(json).unwrap();
+        let invalid_result = result.validate_datetimes();
+        result.slots[0].alternatives.clear();
+        let valid_result = result.validate_datetimes();
+
+        // Then
+        assert_eq!(
+            "Invalid datetime value: 'tomorrow'",
+            invalid_result.unwrap_err().to_string()
+        );
+        assert!(valid_result.is_ok());
+    }
+}
@@ -0,0 +1,477 @@
+use crate::errors::*;
+use crate::ontology::*;
+use failure::{bail, format_err};
+use std::convert::TryFrom;
+use std::ops::{Add, Neg};
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+/// Average length of a gregorian year, i.e. 365.2425 days
+const SECONDS_PER_YEAR: i64 = 31_556_952;
+const SECONDS_PER_MONTH: i64 = SECONDS_PER_YEAR / 12;
+
+impl DurationValue {
+    /// Returns the total number of months of the calendar part of the duration, which is made of
+    /// years, quarters and months, or `None` when it overflows
+    pub fn calendar_months(&self) -> Option<i64> {
+        self.years
+            .checked_mul(12)?
+            .checked_add(self.quarters.checked_mul(3)?)?
+            .checked_add(self.months)
+    }
+
+    /// Returns the total number of seconds of the fixed-length part of the duration, which is
+    /// made of weeks, days, hours, minutes and seconds, or `None` when it overflows
+    pub fn fixed_seconds(&self) -> Option<i64> {
+        self.weeks
+            .checked_mul(SECONDS_PER_WEEK)?
+            .checked_add(self.days.checked_mul(SECONDS_PER_DAY)?)?
+            .checked_add(self.hours.checked_mul(SECONDS_PER_HOUR)?)?
+            .checked_add(self.minutes.checked_mul(SECONDS_PER_MINUTE)?)?
+            .checked_add(self.seconds)
+    }
+
+    /// Returns an equivalent duration where each component is carried over to the next larger
+    /// one, without mixing the calendar part and the fixed-length part
+    ///
+    /// For instance, 90 minutes and 14 months become 1 hour 30 minutes and 1 year 2 months.
+    /// Months are carried over to quarters only by groups of 3 which do not fit in a year. It
+    /// fails when the total number of months or seconds overflows.
+    pub fn normalized(&self) -> Result<DurationValue> {
+        let overflow = || format_err!("Duration overflow: {:?}", self);
+        let (months, quarters, years) =
+            split_signed(self.calendar_months().ok_or_else(overflow)?, &[3, 4]);
+        Ok(DurationValue {
+            years,
+            quarters,
+            months,
+            precision: self.precision,
+            ..DurationValue::from_seconds(self.fixed_seconds().ok_or_else(overflow)?)
+        })
+    }
+
+    /// Returns the approximate number of seconds of the duration, using the average lengths of
+    /// gregorian months and years for the calendar part, or `None` when it overflows
+    pub fn approximate_seconds(&self) -> Option<i64> {
+        self.calendar_months()?
+            .checked_mul(SECONDS_PER_MONTH)?
+            .checked_add(self.fixed_seconds()?)
+    }
+
+    /// Returns the exact normalized duration made of `seconds`
+    pub(crate) fn from_seconds(seconds: i64) -> DurationValue {
+        let parts = split_all_signed(seconds, &[60, 60, 24, 7]);
+        DurationValue {
+            years: 0,
+            quarters: 0,
+            months: 0,
+            weeks: parts[4],
+            days: parts[3],
+            hours: parts[2],
+            minutes: parts[1],
+            seconds: parts[0],
+            precision: Precision::Exact,
+        }
+    }
+
+    /// Converts the duration to a `std::time::Duration`
+    ///
+    /// This conversion is lossy as it relies on `approximate_seconds`. It fails when the
+    /// duration is negative or overflows.
+    pub fn to_std_duration(&self) -> Result<::std::time::Duration> {
+        let seconds = self
+            .approximate_seconds()
+            .ok_or_else(|| format_err!("Duration overflow: {:?}", self))?;
+        match u64::try_from(seconds) {
+            Ok(seconds) => Ok(::std::time::Duration::from_secs(seconds)),
+            Err(_) => bail!(
+                "Cannot convert negative duration to std::time::Duration: {:?}",
+                self
+            ),
+        }
+    }
+
+    /// Returns the ISO-8601 representation of the duration, for instance "P3M" or "P1DT2H"
+    ///
+    /// Quarters are expressed as months, as ISO-8601 does not define them. Months are computed
+    /// on 128 bits so that any duration can be represented.
+    pub fn to_iso8601_string(&self) -> String {
+        let is_negative =
+            self.components().iter().all(|c| *c <= 0) && self.components().iter().any(|c| *c < 0);
+        let months = i128::from(self.quarters) * 3 + i128::from(self.months);
+        let calendar = [
+            (i128::from(self.years), 'Y'),
+            (months, 'M'),
+            (i128::from(self.weeks), 'W'),
+            (i128::from(self.days), 'D'),
+        ];
+        let time = [
+            (i128::from(self.hours), 'H'),
+            (i128::from(self.minutes), 'M'),
+            (i128::from(self.seconds), 'S'),
+        ];
+        // The sign is only written once for negative durations, in front of the "P"
+        let format_value = |value: i128| {
+            if is_negative {
+                value.unsigned_abs().to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        let mut result = if is_negative { "-P" } else { "P" }.to_string();
+        for (value, designator) in calendar.iter().filter(|(v, _)| *v != 0) {
+            result.push_str(&format!("{}{}", format_value(*value), designator));
+        }
+        if time.iter().any(|(v, _)| *v != 0) {
+            result.push('T');
+            for (value, designator) in time.iter().filter(|(v, _)| *v != 0) {
+                result.push_str(&format!("{}{}", format_value(*value), designator));
+            }
+        }
+        if result.ends_with('P') {
+            result.push_str("T0S");
+        }
+        result
+    }
+
+    /// Parses an ISO-8601 duration such as "P3M" or "-P1Y2M10DT2H30M"
+    ///
+    /// Designators must appear in the ISO-8601 order, at most once each, and components cannot
+    /// be negative when the duration has a leading sign. Fractional values are not supported. The
+    /// resulting precision is `Precision::Exact`.
+    pub fn from_iso8601_str(input: &str) -> Result<DurationValue> {
+        let invalid = || format_err!("Invalid ISO-8601 duration: '{}'", input);
+        let (sign, unsigned) = if let Some(stripped) = input.strip_prefix('-') {
+            (-1, stripped)
+        } else {
+            (1, input)
+        };
+        let body = unsigned.strip_prefix('P').ok_or_else(invalid)?;
+        if body.is_empty() || body.ends_with('T') {
+            return Err(invalid());
+        }
+
+        let mut duration = DurationValue {
+            years: 0,
+            quarters: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            precision: Precision::Exact,
+        };
+        let mut in_time_part = false;
+        let mut last_position = None;
+        let mut number = String::new();
+        for c in body.chars() {
+            match c {
+                'T' if !in_time_part && number.is_empty() => in_time_part = true,
+                '-' if sign < 0 => return Err(invalid()),
+                '-' | '0'..='9' => number.push(c),
+                designator => {
+                    let value = sign * number.parse::<i64>().map_err(|_| invalid())?;
+                    number.clear();
+                    let (position, component) = match (in_time_part, designator) {
+                        (false, 'Y') => (0, &mut duration.years),
+                        (false, 'M') => (1, &mut duration.months),
+                        (false, 'W') => (2, &mut duration.weeks),
+                        (false, 'D') => (3, &mut duration.days),
+                        (true, 'H') => (4, &mut duration.hours),
+                        (true, 'M') => (5, &mut duration.minutes),
+                        (true, 'S') => (6, &mut duration.seconds),
+                        _ => return Err(invalid()),
+                    };
+                    if last_position.is_some_and(|last| position <= last) {
+                        return Err(invalid());
+                    }
+                    last_position = Some(position);
+                    *component = value;
+                }
+            }
+        }
+        if !number.is_empty() {
+            return Err(invalid());
+        }
+        Ok(duration)
+    }
+
+    fn components(&self) -> [i64; 8] {
+        [
+            self.years,
+            self.quarters,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ]
+    }
+}
+
+/// Splits `value` in 3 parts using the two successive `ratios`, the last part absorbing the
+/// remainder
+fn split_signed(value: i64, ratios: &[i64; 2]) -> (i64, i64, i64) {
+    let parts = split_all_signed(value, ratios);
+    (parts[0], parts[1], parts[2])
+}
+
+fn split_all_signed(value: i64, ratios: &[i64]) -> Vec<i64> {
+    let sign = value.signum();
+    let mut remaining = value.unsigned_abs();
+    let mut parts = Vec::with_capacity(ratios.len() + 1);
+    for ratio in ratios {
+        let ratio = ratio.unsigned_abs();
+        parts.push(sign * (remaining % ratio) as i64);
+        remaining /= ratio;
+    }
+    parts.push(sign * remaining as i64);
+    parts
+}
+
+impl From<::std::time::Duration> for DurationValue {
+    /// Sub-second precision is dropped, and durations exceeding `i64::MAX` seconds are
+    /// saturated
+    fn from(duration: ::std::time::Duration) -> Self {
+        DurationValue::from_seconds(i64::try_from(duration.as_secs()).unwrap_or(i64::MAX))
+    }
+}
+
+impl DurationValue {
+    /// Adds the durations component-wise, or returns `None` when a component overflows
+    ///
+    /// The result is exact only if both operands are.
+    pub fn checked_add(&self, other: &DurationValue) -> Option<DurationValue> {
+        let precision = match (self.precision, other.precision) {
+            (Precision::Exact, Precision::Exact) => Precision::Exact,
+            _ => Precision::Approximate,
+        };
+        Some(DurationValue {
+            years: self.years.checked_add(other.years)?,
+            quarters: self.quarters.checked_add(other.quarters)?,
+            months: self.months.checked_add(other.months)?,
+            weeks: self.weeks.checked_add(other.weeks)?,
+            days: self.days.checked_add(other.days)?,
+            hours: self.hours.checked_add(other.hours)?,
+            minutes: self.minutes.checked_add(other.minutes)?,
+            seconds: self.seconds.checked_add(other.seconds)?,
+            precision,
+        })
+    }
+
+    /// Negates each component, or returns `None` when one of them is `i64::MIN`
+    pub fn checked_neg(&self) -> Option<DurationValue> {
+        Some(DurationValue {
+            years: self.years.checked_neg()?,
+            quarters: self.quarters.checked_neg()?,
+            months: self.months.checked_neg()?,
+            weeks: self.weeks.checked_neg()?,
+            days: self.days.checked_neg()?,
+            hours: self.hours.checked_neg()?,
+            minutes: self.minutes.checked_neg()?,
+            seconds: self.seconds.checked_neg()?,
+            precision: self.precision,
+        })
+    }
+}
+
+impl Add for DurationValue {
+    type Output = DurationValue;
+
+    /// Adds the durations component-wise, see `checked_add`
+    ///
+    /// # Panics
+    ///
+    /// Panics when a component overflows.
+    fn add(self, other: DurationValue) -> DurationValue {
+        self.checked_add(&other).expect("Duration overflow")
+    }
+}
+
+impl Neg for DurationValue {
+    type Output = DurationValue;
+
+    /// Negates each component, see `checked_neg`
+    ///
+    /// # Panics
+    ///
+    /// Panics when a component is `i64::MIN`.
+    fn neg(self) -> DurationValue {
+        self.checked_neg().expect("Duration overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn duration(components: [i64; 8]) -> DurationValue {
+        DurationValue {
+            years: components[0],
+            quarters: components[1],
+            months: components[2],
+            weeks: components[3],
+            days: components[4],
+            hours: components[5],
+            minutes: components[6],
+            seconds: components[7],
+            precision: Precision::Exact,
+        }
+    }
+
+    #[test]
+    fn test_normalized() {
+        // Given
+        let value = duration([0, 1, 14, 0, 9, 23, 90, 75]);
+
+        // When
+        let normalized = value.normalized().unwrap();
+
+        // Then
+        assert_eq!(duration([1, 1, 2, 1, 3, 0, 31, 15]), normalized);
+        assert_eq!(
+            value.approximate_seconds(),
+            normalized.approximate_seconds()
+        );
+    }
+
+    #[test]
+    fn test_normalized_negative_duration() {
+        assert_eq!(
+            duration([0, 0, 0, 0, 0, -1, -30, 0]),
+            (-duration([0, 0, 0, 0, 0, 0, 90, 0])).normalized().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        // Given
+        let one_month = duration([0, 0, 1, 0, 0, 0, 0, 0]);
+        let mut two_days = duration([0, 0, 0, 0, 2, 0, 0, 0]);
+        two_days.precision = Precision::Approximate;
+
+        // When
+        let sum = one_month + two_days;
+
+        // Then
+        let mut expected_sum = duration([0, 0, 1, 0, 2, 0, 0, 0]);
+        expected_sum.precision = Precision::Approximate;
+        assert_eq!(expected_sum, sum);
+    }
+
+    #[test]
+    fn test_to_std_duration() {
+        assert_eq!(
+            ::std::time::Duration::from_secs(90061),
+            duration([0, 0, 0, 0, 1, 1, 1, 1])
+                .to_std_duration()
+                .unwrap()
+        );
+        assert!((-duration([0, 0, 0, 0, 1, 0, 0, 0]))
+            .to_std_duration()
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_std_duration() {
+        assert_eq!(
+            duration([0, 0, 0, 1, 1, 1, 1, 1]),
+            DurationValue::from(::std::time::Duration::from_secs(694861))
+        );
+    }
+
+    #[test]
+    fn test_iso8601_round_trip() {
+        let cases = vec![
+            (duration([0, 0, 3, 0, 0, 0, 0, 0]), "P3M"),
+            (duration([1, 0, 2, 0, 10, 2, 30, 0]), "P1Y2M10DT2H30M"),
+            (duration([0, 0, 0, 2, 0, 0, 0, 0]), "P2W"),
+            (duration([0, 0, 0, 0, 0, 0, 0, 0]), "PT0S"),
+            (-duration([0, 0, 1, 0, 2, 0, 0, 0]), "-P1M2D"),
+        ];
+        for (value, iso8601) in cases {
+            assert_eq!(iso8601, value.to_iso8601_string());
+            assert_eq!(value, DurationValue::from_iso8601_str(iso8601).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_quarters_are_expressed_as_months_in_iso8601() {
+        assert_eq!(
+            "P7M",
+            duration([0, 2, 1, 0, 0, 0, 0, 0]).to_iso8601_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_iso8601_durations() {
+        let inputs = [
+            "", "P", "3M", "PT", "P3", "P3H", "PT3D", "P1.5Y", "P1Y2Y", "P1D2Y", "PT1S2M", "-P-1Y",
+        ];
+        for input in &inputs {
+            assert!(DurationValue::from_iso8601_str(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_overflowing_durations() {
+        // Given
+        let value = duration([i64::MAX, 0, 0, 0, 0, 0, 0, 0]);
+        let other_value = duration([0, 0, 0, i64::MAX / 2, 0, 0, 0, 0]);
+
+        // When/Then
+        assert_eq!(None, value.calendar_months());
+        assert_eq!(None, value.approximate_seconds());
+        assert!(value.normalized().is_err());
+        assert!(value.to_std_duration().is_err());
+        assert_eq!(None, other_value.fixed_seconds());
+        assert_eq!(
+            duration([0, 0, 0, 15_250_284_452_471, 3, 15, 30, 7]),
+            duration([0, 0, 0, 0, 0, 0, 0, i64::MAX])
+                .normalized()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic_with_extreme_values() {
+        // Given
+        let max = duration([i64::MAX, 0, 0, 0, 0, 0, 0, 1]);
+        let min = duration([0, 0, 0, 0, 0, 0, 0, i64::MIN]);
+        let one_year = duration([1, 0, 0, 0, 0, 0, 0, 0]);
+
+        // When/Then
+        assert_eq!(None, max.checked_add(&one_year));
+        assert_eq!(None, min.checked_neg());
+        assert_eq!(
+            Some(duration([-i64::MAX, 0, 0, 0, 0, 0, 0, -1])),
+            max.checked_neg()
+        );
+        assert_eq!(
+            Some(duration([i64::MAX, 0, 0, 0, 0, 0, 0, 1])),
+            max.checked_neg().unwrap().checked_neg()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Duration overflow")]
+    fn test_overflowing_addition_panics() {
+        let _ = duration([i64::MAX, 0, 0, 0, 0, 0, 0, 0]) + duration([1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_iso8601_string_of_extreme_values() {
+        // Given
+        let max_months = duration([0, i64::MAX, i64::MAX, 0, 0, 0, 0, 0]);
+        let min_seconds = duration([0, 0, 0, 0, 0, 0, 0, i64::MIN]);
+
+        // When/Then
+        assert_eq!("P36893488147419103228M", max_months.to_iso8601_string());
+        assert_eq!("-PT9223372036854775808S", min_seconds.to_iso8601_string());
+    }
+}
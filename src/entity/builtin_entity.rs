@@ -1,14 +1,20 @@
+use crate::entity::examples;
 use crate::enum_kind;
 use crate::errors::*;
+use crate::language::Language;
+use crate::localization;
 use crate::ontology::*;
-use failure::format_err;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json;
+use std::convert::TryFrom;
 use std::ops::Range;
+use std::sync::OnceLock;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BuiltinEntity {
     pub value: String,
+    /// Char range of the entity in the parsed input, see `RangeUnit` to convert it
     pub range: Range<usize>,
     pub entity: SlotValue,
     pub alternatives: Vec<SlotValue>,
@@ -16,6 +22,10 @@ pub struct BuiltinEntity {
         serialize_with = "serialize_builtin_entity_kind",
         deserialize_with = "deserialize_builtin_entity_kind"
     )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(schema_with = "crate::schema::builtin_entity_kind_schema")
+    )]
     pub entity_kind: BuiltinEntityKind,
 }
 
@@ -39,6 +49,110 @@ where
         .and_then(|s| BuiltinEntityKind::from_identifier(&s).map_err(::serde::de::Error::custom))
 }
 
+/// Builtin entity which may have been produced by a more recent version of the ontology, whose
+/// kind and values are deserialized leniently
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LenientBuiltinEntity {
+    pub value: String,
+    /// Char range of the entity in the parsed input, see `RangeUnit` to convert it
+    pub range: Range<usize>,
+    #[serde(deserialize_with = "crate::lenient::deserialize_lenient")]
+    pub entity: SlotValue,
+    #[serde(deserialize_with = "crate::lenient::deserialize_all_lenient")]
+    pub alternatives: Vec<SlotValue>,
+    pub entity_kind: LenientEntityKind,
+}
+
+impl From<BuiltinEntity> for LenientBuiltinEntity {
+    fn from(entity: BuiltinEntity) -> Self {
+        LenientBuiltinEntity {
+            value: entity.value,
+            range: entity.range,
+            entity: entity.entity,
+            alternatives: entity.alternatives,
+            entity_kind: entity.entity_kind.into(),
+        }
+    }
+}
+
+impl TryFrom<LenientBuiltinEntity> for BuiltinEntity {
+    type Error = failure::Error;
+
+    /// Fails when the kind of the entity is unknown to this version of the ontology
+    fn try_from(entity: LenientBuiltinEntity) -> Result<Self> {
+        let entity_kind = match entity.entity_kind {
+            LenientEntityKind::Known(kind) => kind,
+            LenientEntityKind::Unknown(identifier) => {
+                return Err(OntologyError::UnknownEntityIdentifier(identifier).into())
+            }
+        };
+        Ok(BuiltinEntity {
+            value: entity.value,
+            range: entity.range,
+            entity: entity.entity,
+            alternatives: entity.alternatives,
+            entity_kind,
+        })
+    }
+}
+
+/// Kind of a `LenientBuiltinEntity`, which is unknown when the entity was produced by a more
+/// recent version of the ontology
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LenientEntityKind {
+    Known(BuiltinEntityKind),
+    /// Identifier of an entity unknown to this version of the ontology
+    Unknown(String),
+}
+
+impl LenientEntityKind {
+    /// Parses an entity identifier, keeping the ones unknown to this version of the ontology
+    pub fn from_identifier(identifier: &str) -> Self {
+        BuiltinEntityKind::from_identifier(identifier)
+            .map(LenientEntityKind::Known)
+            .unwrap_or_else(|_| LenientEntityKind::Unknown(identifier.to_string()))
+    }
+
+    pub fn identifier(&self) -> &str {
+        match self {
+            LenientEntityKind::Known(kind) => kind.identifier(),
+            LenientEntityKind::Unknown(identifier) => identifier,
+        }
+    }
+
+    /// Returns the entity kind, if it is known to this version of the ontology
+    pub fn known(&self) -> Option<BuiltinEntityKind> {
+        match self {
+            LenientEntityKind::Known(kind) => Some(*kind),
+            LenientEntityKind::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<BuiltinEntityKind> for LenientEntityKind {
+    fn from(kind: BuiltinEntityKind) -> Self {
+        LenientEntityKind::Known(kind)
+    }
+}
+
+impl Serialize for LenientEntityKind {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.identifier())
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientEntityKind {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|s| LenientEntityKind::from_identifier(&s))
+    }
+}
+
 enum_kind!(
     BuiltinEntityKind,
     [
@@ -73,9 +187,25 @@ pub trait IntoBuiltinEntityKind: Copy {
         self.into_builtin_kind().description()
     }
 
+    fn description_in(&self, language: Language) -> &'static str {
+        self.into_builtin_kind().description_in(language)
+    }
+
     fn result_description(&self) -> String {
         self.into_builtin_kind().result_description()
     }
+
+    fn supported_languages(&self) -> &'static [Language] {
+        self.into_builtin_kind().supported_languages()
+    }
+
+    fn examples(&self, language: Language) -> &'static [&'static str] {
+        self.into_builtin_kind().examples(language)
+    }
+
+    fn value_kinds(&self) -> &'static [SlotValueKind] {
+        self.into_builtin_kind().value_kinds()
+    }
 }
 
 impl BuiltinEntityKind {
@@ -101,12 +231,12 @@ impl BuiltinEntityKind {
         }
     }
 
-    pub fn from_identifier(identifier: &str) -> Result<Self> {
+    pub fn from_identifier(identifier: &str) -> OntologyResult<Self> {
         BuiltinEntityKind::all()
             .iter()
             .find(|kind| kind.identifier() == identifier)
-            .map(|k| k.clone())
-            .ok_or(format_err!("Unknown EntityKind identifier: {}", identifier))
+            .cloned()
+            .ok_or_else(|| OntologyError::UnknownEntityIdentifier(identifier.to_string()))
     }
 }
 
@@ -138,6 +268,83 @@ impl BuiltinEntityKind {
             BuiltinEntityKind::Region => "Matches local administrative regions",
         }
     }
+
+    /// Returns the description translated in the given language, or the english description
+    /// when no translation is available
+    pub fn description_in(&self, language: Language) -> &'static str {
+        localization::builtin_entity_description(*self, language)
+            .unwrap_or_else(|| self.description())
+    }
+}
+
+impl BuiltinEntityKind {
+    pub fn supported_languages(&self) -> &'static [Language] {
+        match *self {
+            BuiltinEntityKind::AmountOfMoney => Language::all(),
+            BuiltinEntityKind::Duration => Language::all(),
+            BuiltinEntityKind::Number => Language::all(),
+            BuiltinEntityKind::Ordinal => Language::all(),
+            BuiltinEntityKind::Temperature => Language::all(),
+            BuiltinEntityKind::Datetime => Language::all(),
+            BuiltinEntityKind::Date => Language::all(),
+            BuiltinEntityKind::Time => Language::all(),
+            BuiltinEntityKind::DatePeriod => Language::all(),
+            BuiltinEntityKind::TimePeriod => Language::all(),
+            BuiltinEntityKind::Percentage => Language::all(),
+            BuiltinEntityKind::MusicAlbum => all_languages_but_ko(),
+            BuiltinEntityKind::MusicArtist => all_languages_but_ko(),
+            BuiltinEntityKind::MusicTrack => all_languages_but_ko(),
+            BuiltinEntityKind::City => all_languages_but_ko(),
+            BuiltinEntityKind::Country => all_languages_but_ko(),
+            BuiltinEntityKind::Region => all_languages_but_ko(),
+        }
+    }
+}
+
+impl BuiltinEntityKind {
+    /// Returns the kinds of the slot values which this entity resolves to
+    pub fn value_kinds(&self) -> &'static [SlotValueKind] {
+        match *self {
+            BuiltinEntityKind::AmountOfMoney => &[SlotValueKind::AmountOfMoney],
+            BuiltinEntityKind::Duration => &[SlotValueKind::Duration],
+            BuiltinEntityKind::Number => &[SlotValueKind::Number],
+            BuiltinEntityKind::Ordinal => &[SlotValueKind::Ordinal],
+            BuiltinEntityKind::Temperature => &[SlotValueKind::Temperature],
+            BuiltinEntityKind::Datetime => {
+                &[SlotValueKind::InstantTime, SlotValueKind::TimeInterval]
+            }
+            BuiltinEntityKind::Date => &[SlotValueKind::InstantTime],
+            BuiltinEntityKind::Time => &[SlotValueKind::InstantTime],
+            BuiltinEntityKind::DatePeriod => &[SlotValueKind::TimeInterval],
+            BuiltinEntityKind::TimePeriod => &[SlotValueKind::TimeInterval],
+            BuiltinEntityKind::Percentage => &[SlotValueKind::Percentage],
+            BuiltinEntityKind::MusicAlbum => &[SlotValueKind::MusicAlbum],
+            BuiltinEntityKind::MusicArtist => &[SlotValueKind::MusicArtist],
+            BuiltinEntityKind::MusicTrack => &[SlotValueKind::MusicTrack],
+            BuiltinEntityKind::City => &[SlotValueKind::City],
+            BuiltinEntityKind::Country => &[SlotValueKind::Country],
+            BuiltinEntityKind::Region => &[SlotValueKind::Region],
+        }
+    }
+}
+
+impl BuiltinEntityKind {
+    /// Returns some example phrases matched by this entity in the given language, which are
+    /// empty when the language is not supported
+    pub fn examples(&self, language: Language) -> &'static [&'static str] {
+        examples::builtin_entity_examples(*self, language)
+    }
+}
+
+fn all_languages_but_ko() -> &'static [Language] {
+    static ALL_BUT_KO: OnceLock<Vec<Language>> = OnceLock::new();
+    ALL_BUT_KO.get_or_init(|| {
+        Language::all()
+            .iter()
+            .filter(|language| **language != Language::KO)
+            .cloned()
+            .collect()
+    })
 }
 
 impl BuiltinEntityKind {
@@ -259,7 +466,41 @@ impl BuiltinEntityKind {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entity::gazetteer_entity::*;
+    use crate::entity::grammar_entity::*;
     use serde_test::{assert_tokens, Token};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_entity_kind_resolution_errors() {
+        assert_eq!(
+            Err(OntologyError::UnknownEntityIdentifier(
+                "snips/foo".to_string()
+            )),
+            BuiltinEntityKind::from_identifier("snips/foo")
+        );
+        assert_eq!(
+            Err(OntologyError::UnknownEntityKind {
+                kind: "BuiltinEntityKind",
+                name: "Foo".to_string()
+            }),
+            BuiltinEntityKind::from_str("Foo")
+        );
+        assert_eq!(
+            Err(OntologyError::NotAGrammarEntity("snips/city".to_string())),
+            GrammarEntityKind::from_identifier("snips/city")
+        );
+        assert_eq!(
+            Err(OntologyError::NotAGazetteerEntity(
+                "snips/number".to_string()
+            )),
+            BuiltinEntityKind::Number.try_into_gazetteer_kind()
+        );
+        assert_eq!(
+            Ok(GrammarEntityKind::Number),
+            BuiltinEntityKind::Number.try_into_grammar_kind()
+        );
+    }
 
     #[test]
     fn test_result_descriptions() {
@@ -272,6 +513,76 @@ mod tests {
         assert_eq!(expected_description, description);
     }
 
+    #[test]
+    fn test_value_kinds_match_result_descriptions() {
+        for kind in BuiltinEntityKind::all() {
+            // Given
+            let values: Vec<SlotValue> = serde_json::from_str(&kind.result_description()).unwrap();
+
+            // When
+            let value_kinds = kind.value_kinds();
+
+            // Then
+            for value in values {
+                assert!(
+                    value_kinds.contains(&value.kind()),
+                    "Unexpected value kind {:?} for {:?}",
+                    value.kind(),
+                    kind
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_supported_languages() {
+        // Given
+        let music_album = BuiltinEntityKind::MusicAlbum;
+        let number = BuiltinEntityKind::Number;
+
+        // When/Then
+        assert!(!music_album.supported_languages().contains(&Language::KO));
+        assert!(music_album.supported_languages().contains(&Language::JA));
+        assert_eq!(Language::all(), number.supported_languages());
+    }
+
+    #[test]
+    fn test_examples_are_provided_for_supported_languages() {
+        for kind in BuiltinEntityKind::all() {
+            for language in Language::all() {
+                let is_supported = kind.supported_languages().contains(language);
+                assert_eq!(
+                    is_supported,
+                    !kind.examples(*language).is_empty(),
+                    "Inconsistent examples for {:?} in {:?}",
+                    kind,
+                    language
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_localized_descriptions() {
+        // Given
+        let entity_kind = BuiltinEntityKind::Duration;
+
+        // When/Then
+        assert_eq!(
+            "Reconnaît une durée",
+            entity_kind.description_in(Language::FR)
+        );
+        assert_eq!(
+            entity_kind.description(),
+            entity_kind.description_in(Language::EN)
+        );
+        for kind in BuiltinEntityKind::all() {
+            for language in Language::all() {
+                assert!(!kind.description_in(*language).is_empty());
+            }
+        }
+    }
+
     #[test]
     fn test_builtin_entity_ser_de() {
         let entity = BuiltinEntity {
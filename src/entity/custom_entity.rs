@@ -0,0 +1,161 @@
+/// Description of a custom entity, serialized as declared in a training dataset
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CustomEntityDescriptor {
+    /// Name of the entity, which is omitted in a dataset where the entity is keyed by its name
+    #[serde(default)]
+    pub name: String,
+    /// Whether values which are not listed can be extracted
+    #[serde(default = "default_true")]
+    pub automatically_extensible: bool,
+    /// Whether the synonyms of the values are resolved to their value
+    #[serde(default = "default_true")]
+    pub use_synonyms: bool,
+    /// Ratio between 0 and 1 of the tokens of a value which must be matched, 1 meaning that
+    /// values are matched exactly
+    #[serde(default = "default_matching_strictness")]
+    pub matching_strictness: f32,
+    #[serde(default, rename = "data")]
+    pub values: Vec<EntityValue>,
+}
+
+/// Value of a custom entity along with its synonyms
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityValue {
+    pub value: String,
+    #[serde(default)]
+    pub synonyms: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_matching_strictness() -> f32 {
+    1.0
+}
+
+impl CustomEntityDescriptor {
+    /// Creates an automatically extensible entity without values, using synonyms and matching
+    /// values exactly
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        CustomEntityDescriptor {
+            name: name.into(),
+            automatically_extensible: true,
+            use_synonyms: true,
+            matching_strictness: default_matching_strictness(),
+            values: vec![],
+        }
+    }
+
+    /// Returns the value which the raw value refers to, either directly or through one of its
+    /// synonyms when they are used
+    pub fn resolve_value(&self, raw_value: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|entity_value| {
+                entity_value.value == raw_value
+                    || (self.use_synonyms && entity_value.synonyms.iter().any(|s| s == raw_value))
+            })
+            .map(|entity_value| &*entity_value.value)
+    }
+}
+
+impl EntityValue {
+    pub fn new<S: Into<String>>(value: S, synonyms: Vec<String>) -> Self {
+        EntityValue {
+            value: value.into(),
+            synonyms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_custom_entity_with_defaults() {
+        // Given
+        let json = r#"{
+            "data": [{"value": "coffee", "synonyms": ["espresso"]}, {"value": "tea"}]
+        }"#;
+
+        // When
+        let entity: CustomEntityDescriptor = serde_json::from_str(json).unwrap();
+
+        // Then
+        let expected_entity = CustomEntityDescriptor {
+            values: vec![
+                EntityValue::new("coffee", vec!["espresso".to_string()]),
+                EntityValue::new("tea", vec![]),
+            ],
+            ..CustomEntityDescriptor::new("")
+        };
+        assert_eq!(expected_entity, entity);
+    }
+
+    #[test]
+    fn test_serialize_custom_entity() {
+        // Given
+        let entity = CustomEntityDescriptor {
+            values: vec![EntityValue::new("tea", vec![])],
+            ..CustomEntityDescriptor::new("beverage")
+        };
+
+        // When
+        let json = serde_json::to_value(&entity).unwrap();
+
+        // Then
+        let expected_json = serde_json::json!({
+            "name": "beverage",
+            "automatically_extensible": true,
+            "use_synonyms": true,
+            "matching_strictness": 1.0,
+            "data": [{"value": "tea", "synonyms": []}]
+        });
+        assert_eq!(expected_json, json);
+    }
+
+    #[test]
+    fn test_custom_entity_round_trip() {
+        // Given
+        let entity = CustomEntityDescriptor {
+            matching_strictness: 0.5,
+            values: vec![EntityValue::new("coffee", vec!["espresso".to_string()])],
+            ..CustomEntityDescriptor::new("beverage")
+        };
+
+        // When
+        let json = serde_json::to_string(&entity).unwrap();
+
+        // Then
+        assert_eq!(entity, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_resolve_value() {
+        // Given
+        let entity = CustomEntityDescriptor {
+            values: vec![EntityValue::new("coffee", vec!["espresso".to_string()])],
+            ..CustomEntityDescriptor::new("beverage")
+        };
+        let entity_without_synonyms = CustomEntityDescriptor {
+            use_synonyms: false,
+            ..entity.clone()
+        };
+
+        // When
+        let resolved_values = vec![
+            entity.resolve_value("coffee"),
+            entity.resolve_value("espresso"),
+            entity.resolve_value("tea"),
+        ];
+        let resolved_synonym = entity_without_synonyms.resolve_value("espresso");
+
+        // Then
+        assert_eq!(vec![Some("coffee"), Some("coffee"), None], resolved_values);
+        assert_eq!(None, resolved_synonym);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::entity::custom_entity::CustomEntityDescriptor;
+use crate::errors::*;
+use crate::ontology::Slot;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Prefix of the identifiers of the builtin entities
+pub const BUILTIN_ENTITY_PREFIX: &str = "snips/";
+
+/// Entity referenced by a slot, identified as in `Slot::entity`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EntityRef {
+    Builtin(BuiltinEntityKind),
+    /// Name of a custom entity
+    Custom(String),
+}
+
+impl EntityRef {
+    /// Parses an entity identifier, builtin entities being prefixed with "snips/"
+    pub fn from_identifier(identifier: &str) -> OntologyResult<Self> {
+        if identifier.starts_with(BUILTIN_ENTITY_PREFIX) {
+            BuiltinEntityKind::from_identifier(identifier).map(EntityRef::Builtin)
+        } else {
+            Ok(EntityRef::Custom(identifier.to_string()))
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        match self {
+            EntityRef::Builtin(kind) => kind.identifier(),
+            EntityRef::Custom(name) => name,
+        }
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        match self {
+            EntityRef::Builtin(_) => true,
+            EntityRef::Custom(_) => false,
+        }
+    }
+}
+
+impl From<BuiltinEntityKind> for EntityRef {
+    fn from(kind: BuiltinEntityKind) -> Self {
+        EntityRef::Builtin(kind)
+    }
+}
+
+impl From<&CustomEntityDescriptor> for EntityRef {
+    fn from(entity: &CustomEntityDescriptor) -> Self {
+        EntityRef::Custom(entity.name.clone())
+    }
+}
+
+impl FromStr for EntityRef {
+    type Err = OntologyError;
+
+    fn from_str(identifier: &str) -> OntologyResult<Self> {
+        EntityRef::from_identifier(identifier)
+    }
+}
+
+impl fmt::Display for EntityRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.identifier())
+    }
+}
+
+impl Serialize for EntityRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.identifier())
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityRef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)
+            .and_then(|s| EntityRef::from_identifier(&s).map_err(de::Error::custom))
+    }
+}
+
+impl Slot {
+    /// Returns the entity referenced by the slot
+    pub fn entity_ref(&self) -> OntologyResult<EntityRef> {
+        EntityRef::from_identifier(&self.entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entity_refs() {
+        // Given
+        let identifiers = ["snips/musicAlbum", "beverage", "snips/unknown"];
+
+        // When
+        let entity_refs: Vec<_> = identifiers
+            .iter()
+            .map(|identifier| EntityRef::from_identifier(identifier))
+            .collect();
+
+        // Then
+        let expected_entity_refs = vec![
+            Ok(EntityRef::Builtin(BuiltinEntityKind::MusicAlbum)),
+            Ok(EntityRef::Custom("beverage".to_string())),
+            Err(OntologyError::UnknownEntityIdentifier(
+                "snips/unknown".to_string(),
+            )),
+        ];
+        assert_eq!(expected_entity_refs, entity_refs);
+    }
+
+    #[test]
+    fn test_slot_entity_ref() {
+        // Given
+        let slot = Slot::new_custom(
+            "coffee".to_string(),
+            0..6,
+            "beverage".to_string(),
+            "drink".to_string(),
+            None,
+            vec![],
+        );
+
+        // When
+        let entity_ref = slot.entity_ref().unwrap();
+
+        // Then
+        assert_eq!(EntityRef::Custom("beverage".to_string()), entity_ref);
+        assert!(!entity_ref.is_builtin());
+        assert_eq!(
+            r#"["beverage","snips/number"]"#,
+            serde_json::to_string(&[entity_ref, BuiltinEntityKind::Number.into()]).unwrap()
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::language::Language;
+
+const MUSIC_ALBUM_EXAMPLES: &[&str] = &["Discovery", "Abbey Road", "Back in Black"];
+const MUSIC_ARTIST_EXAMPLES: &[&str] = &["Daft Punk", "Beyoncé", "The Rolling Stones"];
+const MUSIC_TRACK_EXAMPLES: &[&str] = &["Harder Better Faster Stronger", "Let It Be", "Despacito"];
+
+pub(crate) fn builtin_entity_examples(
+    entity_kind: BuiltinEntityKind,
+    language: Language,
+) -> &'static [&'static str] {
+    use crate::entity::builtin_entity::BuiltinEntityKind::*;
+    use crate::language::Language::*;
+
+    match (entity_kind, language) {
+        (AmountOfMoney, DE) => &["10$", "ungefähr 5€", "zwei tausend Dollar"],
+        (AmountOfMoney, EN) => &[
+            "$10",
+            "six euros",
+            "around 5€",
+            "ten dollars and five cents",
+        ],
+        (AmountOfMoney, ES) => &["10$", "cinco euros", "diez dólares y cinco centavos"],
+        (AmountOfMoney, FR) => &["10$", "environ 5€", "dix dollars et cinq centimes"],
+        (AmountOfMoney, IT) => &["10$", "circa 5€", "dieci dollari e cinque centesimi"],
+        (AmountOfMoney, PT_PT) => &["10$", "cerca de 5€", "dez dólares e cinco centavos"],
+        (AmountOfMoney, PT_BR) => &["10$", "cerca de 5€", "dez dólares e cinco centavos"],
+        (AmountOfMoney, JA) => &["八ドル", "五十二アメリカドル"],
+        (AmountOfMoney, KO) => &["10$", "약 5 유로", "십 달러 오 센트"],
+
+        (Duration, DE) => &[
+            "2stdn",
+            "drei Monate",
+            "eine halbe Stunde",
+            "8 Jahre und zwei Tage",
+        ],
+        (Duration, EN) => &[
+            "1h",
+            "during two minutes",
+            "for 20 seconds",
+            "3 months",
+            "half an hour",
+            "8 years and two days",
+        ],
+        (Duration, ES) => &["1h", "3 meses", "media hora", "ocho años y dos días"],
+        (Duration, FR) => &[
+            "1h",
+            "dans trois heures",
+            "pendant vingt minutes",
+            "8 ans et deux jours",
+        ],
+        (Duration, IT) => &["1h", "3 mesi", "per venti minuti", "otto anni e due giorni"],
+        (Duration, PT_PT) => &[
+            "1h",
+            "durante vinte minutos",
+            "3 meses",
+            "oito anos e dois dias",
+        ],
+        (Duration, PT_BR) => &[
+            "1h",
+            "durante vinte minutos",
+            "3 meses",
+            "oito anos e dois dias",
+        ],
+        (Duration, JA) => &["一秒間", "五日間", "十ヶ月間"],
+        (Duration, KO) => &["양일", "1시간", "3 개월"],
+
+        (Number, DE) => &[
+            "2001",
+            "einundzwanzig",
+            "zwei tausend",
+            "drei hundert und vier",
+        ],
+        (Number, EN) => &["2001", "twenty one", "three hundred and four"],
+        (Number, ES) => &["2001", "dieciocho", "ciento dos"],
+        (Number, FR) => &["2001", "vingt-deux", "deux cent trois"],
+        (Number, IT) => &["2001", "diciotto", "duecento"],
+        (Number, PT_PT) => &["2001", "dezoito", "cento e dois"],
+        (Number, PT_BR) => &["2001", "dezoito", "cento e dois"],
+        (Number, JA) => &["十二", "二千五", "四千三百二"],
+        (Number, KO) => &["2001", "삼천", "스물 둘", "천 아흔 아홉"],
+
+        (Ordinal, DE) => &["Erste", "der zweite", "zwei und zwanzigster"],
+        (Ordinal, EN) => &["1st", "the second", "the twenty third"],
+        (Ordinal, ES) => &["primer", "segundo", "vigésimo primero"],
+        (Ordinal, FR) => &["1er", "43ème", "vingt-deuxième"],
+        (Ordinal, IT) => &["primo", "secondo", "ventitreesimo"],
+        (Ordinal, PT_PT) => &["primeiro", "segundo", "vigésimo terceiro"],
+        (Ordinal, PT_BR) => &["primeiro", "segundo", "vigésimo terceiro"],
+        (Ordinal, JA) => &["十一番目", "九十一番目"],
+        (Ordinal, KO) => &["첫번째", "두번째"],
+
+        (Temperature, DE) => &[
+            "70K",
+            "3°C",
+            "Dreiundzwanzig Grad",
+            "zweiunddreißig Grad Fahrenheit",
+        ],
+        (Temperature, EN) => &[
+            "70K",
+            "3°C",
+            "Twenty three degrees",
+            "one hundred degrees fahrenheit",
+        ],
+        (Temperature, ES) => &[
+            "70K",
+            "3°C",
+            "veintitrés grados",
+            "treinta y dos grados fahrenheit",
+        ],
+        (Temperature, FR) => &[
+            "70K",
+            "3°C",
+            "vingt-trois degrés",
+            "trente-deux degrés fahrenheit",
+        ],
+        (Temperature, IT) => &["70K", "3°C", "ventitré gradi", "trentadue gradi fahrenheit"],
+        (Temperature, PT_PT) => &[
+            "70K",
+            "3°C",
+            "vinte e três graus",
+            "trinta e dois graus fahrenheit",
+        ],
+        (Temperature, PT_BR) => &[
+            "70K",
+            "3°C",
+            "vinte e três graus",
+            "trinta e dois graus fahrenheit",
+        ],
+        (Temperature, JA) => &["摂氏二十三度", "華氏三十二度"],
+        (Temperature, KO) => &["섭씨 이십삼도", "화씨 삼십이도"],
+
+        (Datetime, DE) => &[
+            "Heute",
+            "um 8 Uhr morgens",
+            "nächsten Montag um 18 Uhr",
+            "vom 18. bis 20. Juni",
+        ],
+        (Datetime, EN) => &[
+            "Today",
+            "at 8 a.m.",
+            "4:30 pm",
+            "in 1 hour",
+            "the 3rd tuesday of June",
+            "from June 18th to June 20th",
+        ],
+        (Datetime, ES) => &[
+            "hoy",
+            "esta noche",
+            "a la 1:30",
+            "el primer jueves de junio",
+        ],
+        (Datetime, FR) => &[
+            "aujourd'hui",
+            "à 14h",
+            "lundi prochain à 18h",
+            "du 18 au 20 juin",
+        ],
+        (Datetime, IT) => &[
+            "oggi",
+            "alle 14",
+            "lunedì prossimo alle 18",
+            "dal 18 al 20 giugno",
+        ],
+        (Datetime, PT_PT) => &[
+            "hoje",
+            "às 14h",
+            "na próxima segunda-feira às 18h",
+            "de 18 a 20 de junho",
+        ],
+        (Datetime, PT_BR) => &[
+            "hoje",
+            "às 14h",
+            "próxima segunda-feira às 18h",
+            "de 18 a 20 de junho",
+        ],
+        (Datetime, JA) => &["今日", "明日の午後三時", "六月十八日から六月二十日まで"],
+        (Datetime, KO) => &["오늘", "내일 오후 3시", "6월 18일부터 6월 20일까지"],
+
+        (Date, DE) => &["heute", "am 3. Juni", "nächsten Montag"],
+        (Date, EN) => &["today", "on Wednesday", "March 26th", "saturday january 19"],
+        (Date, ES) => &["hoy", "el miércoles", "el 26 de marzo"],
+        (Date, FR) => &["aujourd'hui", "mercredi", "le 26 mars"],
+        (Date, IT) => &["oggi", "mercoledì", "il 26 marzo"],
+        (Date, PT_PT) => &["hoje", "na quarta-feira", "26 de março"],
+        (Date, PT_BR) => &["hoje", "na quarta-feira", "26 de março"],
+        (Date, JA) => &["今日", "水曜日", "三月二十六日"],
+        (Date, KO) => &["오늘", "수요일", "3월 26일"],
+
+        (Time, DE) => &["jetzt", "um 8 Uhr morgens", "um 14:30", "in zwei Stunden"],
+        (Time, EN) => &["now", "at noon", "at 8 a.m.", "4:30 pm", "in one hour"],
+        (Time, ES) => &["ahora", "a mediodía", "a las 8 de la mañana", "a las 16:30"],
+        (Time, FR) => &["maintenant", "à midi", "à 8h du matin", "à 16h30"],
+        (Time, IT) => &["adesso", "a mezzogiorno", "alle 8 di mattina", "alle 16:30"],
+        (Time, PT_PT) => &["agora", "ao meio-dia", "às 8 da manhã", "às 16:30"],
+        (Time, PT_BR) => &["agora", "ao meio-dia", "às 8 da manhã", "às 16:30"],
+        (Time, JA) => &["今", "正午", "午前八時", "午後四時半"],
+        (Time, KO) => &["지금", "정오", "오전 8시", "오후 4시 30분"],
+
+        (DatePeriod, DE) => &["Januar", "2019", "von Montag bis Freitag", "im Sommer"],
+        (DatePeriod, EN) => &[
+            "january",
+            "2019",
+            "from monday to friday",
+            "from the 2nd to the 9th of june",
+            "during the summer",
+        ],
+        (DatePeriod, ES) => &["enero", "2019", "de lunes a viernes", "del 2 al 9 de junio"],
+        (DatePeriod, FR) => &["janvier", "2019", "du lundi au vendredi", "du 2 au 9 juin"],
+        (DatePeriod, IT) => &[
+            "gennaio",
+            "2019",
+            "da lunedì a venerdì",
+            "dal 2 al 9 giugno",
+        ],
+        (DatePeriod, PT_PT) => &["janeiro", "2019", "de segunda a sexta", "de 2 a 9 de junho"],
+        (DatePeriod, PT_BR) => &["janeiro", "2019", "de segunda a sexta", "de 2 a 9 de junho"],
+        (DatePeriod, JA) => &["一月", "二千十九年", "月曜日から金曜日まで"],
+        (DatePeriod, KO) => &["1월", "2019년", "월요일부터 금요일까지"],
+
+        (TimePeriod, DE) => &["heute Abend", "von 5 bis 10 Uhr", "zwischen 9 und 10 Uhr"],
+        (TimePeriod, EN) => &["tonight", "from five to ten", "between 9 and 10 am"],
+        (TimePeriod, ES) => &["esta noche", "de 5 a 10", "entre las 9 y las 10"],
+        (TimePeriod, FR) => &["ce soir", "de 5h à 10h", "entre 9h et 10h"],
+        (TimePeriod, IT) => &["stasera", "dalle 5 alle 10", "tra le 9 e le 10"],
+        (TimePeriod, PT_PT) => &["esta noite", "das 5 às 10", "entre as 9 e as 10"],
+        (TimePeriod, PT_BR) => &["hoje à noite", "das 5 às 10", "entre 9 e 10 horas"],
+        (TimePeriod, JA) => &["今晩", "五時から十時まで"],
+        (TimePeriod, KO) => &["오늘 밤", "5시부터 10시까지"],
+
+        (Percentage, DE) => &["25%", "zwanzig Prozent", "zwei tausend und fünfzig Prozent"],
+        (Percentage, EN) => &["25%", "twenty percent", "two hundred and fifty percent"],
+        (Percentage, ES) => &["25%", "veinte por ciento", "tres mil por ciento"],
+        (Percentage, FR) => &["25%", "20 pourcents", "quatre-vingt-dix pour cent"],
+        (Percentage, IT) => &["25%", "venti per cento", "novanta percento"],
+        (Percentage, PT_PT) => &["25%", "vinte por cento", "noventa por cento"],
+        (Percentage, PT_BR) => &["25%", "vinte por cento", "noventa por cento"],
+        (Percentage, JA) => &["二十五パーセント", "八パーセント"],
+        (Percentage, KO) => &["25%", "이십 퍼센트"],
+
+        (MusicAlbum, KO) => &[],
+        (MusicAlbum, _) => MUSIC_ALBUM_EXAMPLES,
+
+        (MusicArtist, KO) => &[],
+        (MusicArtist, _) => MUSIC_ARTIST_EXAMPLES,
+
+        (MusicTrack, KO) => &[],
+        (MusicTrack, _) => MUSIC_TRACK_EXAMPLES,
+
+        (City, DE) => &["Berlin", "München", "Paris"],
+        (City, EN) => &["San Francisco", "Los Angeles", "Beijing", "Paris"],
+        (City, ES) => &["Madrid", "Barcelona", "Nueva York"],
+        (City, FR) => &["Paris", "Lyon", "Londres"],
+        (City, IT) => &["Roma", "Milano", "Parigi"],
+        (City, PT_PT) => &["Lisboa", "Porto", "Londres"],
+        (City, PT_BR) => &["São Paulo", "Rio de Janeiro", "Nova Iorque"],
+        (City, JA) => &["東京", "大阪", "パリ"],
+        (City, KO) => &[],
+
+        (Country, DE) => &["Deutschland", "Frankreich", "Vereinigte Staaten"],
+        (Country, EN) => &["France", "Italy", "United States"],
+        (Country, ES) => &["España", "Francia", "Estados Unidos"],
+        (Country, FR) => &["France", "Allemagne", "États-Unis"],
+        (Country, IT) => &["Italia", "Francia", "Stati Uniti"],
+        (Country, PT_PT) => &["Portugal", "França", "Estados Unidos"],
+        (Country, PT_BR) => &["Brasil", "França", "Estados Unidos"],
+        (Country, JA) => &["日本", "フランス", "アメリカ合衆国"],
+        (Country, KO) => &[],
+
+        (Region, DE) => &["Bayern", "Nordrhein-Westfalen"],
+        (Region, EN) => &["California", "Texas", "Florida"],
+        (Region, ES) => &["Andalucía", "Cataluña"],
+        (Region, FR) => &["Bretagne", "Île-de-France"],
+        (Region, IT) => &["Toscana", "Lombardia"],
+        (Region, PT_PT) => &["Algarve", "Alentejo"],
+        (Region, PT_BR) => &["Bahia", "Minas Gerais"],
+        (Region, JA) => &["北海道", "沖縄県"],
+        (Region, KO) => &[],
+    }
+}
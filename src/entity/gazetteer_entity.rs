@@ -1,19 +1,19 @@
 use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
 use crate::errors::*;
 use crate::sub_entity_kind;
-use failure::format_err;
 
 sub_entity_kind!(
     BuiltinGazetteerEntityKind,
+    NotAGazetteerEntity,
     [City, Country, MusicAlbum, MusicArtist, MusicTrack, Region]
 );
 
 pub trait TryIntoBuiltinGazetteerEntityKind {
-    fn try_into_gazetteer_kind(&self) -> Result<BuiltinGazetteerEntityKind>;
+    fn try_into_gazetteer_kind(&self) -> OntologyResult<BuiltinGazetteerEntityKind>;
 }
 
 impl TryIntoBuiltinGazetteerEntityKind for BuiltinEntityKind {
-    fn try_into_gazetteer_kind(&self) -> Result<BuiltinGazetteerEntityKind> {
+    fn try_into_gazetteer_kind(&self) -> OntologyResult<BuiltinGazetteerEntityKind> {
         BuiltinGazetteerEntityKind::from_identifier(self.identifier())
     }
 }
@@ -1,10 +1,10 @@
 use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
 use crate::errors::*;
 use crate::sub_entity_kind;
-use failure::format_err;
 
 sub_entity_kind!(
     GrammarEntityKind,
+    NotAGrammarEntity,
     [
         AmountOfMoney,
         Duration,
@@ -21,11 +21,11 @@ sub_entity_kind!(
 );
 
 pub trait TryIntoGrammarEntityKind {
-    fn try_into_grammar_kind(self) -> Result<GrammarEntityKind>;
+    fn try_into_grammar_kind(self) -> OntologyResult<GrammarEntityKind>;
 }
 
 impl TryIntoGrammarEntityKind for BuiltinEntityKind {
-    fn try_into_grammar_kind(self) -> Result<GrammarEntityKind> {
+    fn try_into_grammar_kind(self) -> OntologyResult<GrammarEntityKind> {
         GrammarEntityKind::from_identifier(self.identifier())
     }
 }
@@ -1,3 +1,6 @@
 pub mod builtin_entity;
+pub mod custom_entity;
+pub mod entity_ref;
+mod examples;
 pub mod gazetteer_entity;
 pub mod grammar_entity;
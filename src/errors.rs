@@ -1 +1,57 @@
+use crate::range::RangeUnit;
+use std::fmt;
+use std::ops::Range;
+
 pub type Result<T> = ::std::result::Result<T, ::failure::Error>;
+
+pub type OntologyResult<T> = ::std::result::Result<T, OntologyError>;
+
+/// Errors raised when resolving languages and entity kinds, or converting ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OntologyError {
+    /// The language code does not match any supported language
+    UnknownLanguage(String),
+    /// The identifier, such as "snips/number", does not match any builtin entity
+    UnknownEntityIdentifier(String),
+    /// The name, such as "Number", does not match any entity kind
+    UnknownEntityKind { kind: &'static str, name: String },
+    /// The identifier refers to a builtin entity which is not a grammar entity
+    NotAGrammarEntity(String),
+    /// The identifier refers to a builtin entity which is not a gazetteer entity
+    NotAGazetteerEntity(String),
+    /// The range is not a valid range of the input in the given unit
+    InvalidRange {
+        range: Range<usize>,
+        unit: RangeUnit,
+    },
+}
+
+impl fmt::Display for OntologyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OntologyError::UnknownLanguage(language) => write!(f, "Unknown language: {}", language),
+            OntologyError::UnknownEntityIdentifier(identifier) => {
+                write!(f, "Unknown entity identifier: {}", identifier)
+            }
+            OntologyError::UnknownEntityKind { kind, name } => {
+                write!(f, "{} is not a known {}", name, kind)
+            }
+            OntologyError::NotAGrammarEntity(identifier) => {
+                write!(f, "{} is not a grammar entity", identifier)
+            }
+            OntologyError::NotAGazetteerEntity(identifier) => {
+                write!(f, "{} is not a gazetteer entity", identifier)
+            }
+            OntologyError::InvalidRange { range, unit } => {
+                write!(
+                    f,
+                    "{:?} is not a valid {} range of the input",
+                    range,
+                    unit.name()
+                )
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for OntologyError {}
@@ -1,5 +1,8 @@
+use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
+use crate::entity::gazetteer_entity::BuiltinGazetteerEntityKind;
+use crate::entity::grammar_entity::GrammarEntityKind;
 use crate::language_enum;
-use failure::bail;
+use crate::localization;
 
 language_enum!([DE, EN, ES, FR, IT, PT_PT, PT_BR, JA, KO]);
 
@@ -17,11 +20,41 @@ impl Language {
             Language::KO => "Korean",
         }
     }
+
+    /// Returns the name of the language written in `display_language`, or the english name
+    /// when no translation is available
+    pub fn full_name_in(&self, display_language: Language) -> &'static str {
+        localization::language_full_name(*self, display_language)
+            .unwrap_or_else(|| self.full_name())
+    }
+}
+
+impl Language {
+    pub fn supported_builtin_entities(&self) -> Vec<BuiltinEntityKind> {
+        supported_entities(BuiltinEntityKind::all(), *self)
+    }
+
+    pub fn supported_grammar_entities(&self) -> Vec<GrammarEntityKind> {
+        supported_entities(GrammarEntityKind::all(), *self)
+    }
+
+    pub fn supported_gazetteer_entities(&self) -> Vec<BuiltinGazetteerEntityKind> {
+        supported_entities(BuiltinGazetteerEntityKind::all(), *self)
+    }
+}
+
+fn supported_entities<T: IntoBuiltinEntityKind>(kinds: &[T], language: Language) -> Vec<T> {
+    kinds
+        .iter()
+        .filter(|kind| kind.supported_languages().contains(&language))
+        .cloned()
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::errors::OntologyError;
     use std::str::FromStr;
 
     #[test]
@@ -35,4 +68,30 @@ mod test {
         let lang = Language::from_str("EN");
         assert!(lang.is_ok());
     }
+
+    #[test]
+    fn init_from_unknown_string_fails() {
+        let lang = Language::from_str("xx");
+        assert_eq!(Err(OntologyError::UnknownLanguage("xx".to_string())), lang);
+    }
+
+    #[test]
+    fn full_name_in_works() {
+        assert_eq!("Japonais", Language::JA.full_name_in(Language::FR));
+        assert_eq!("Deutsch", Language::DE.full_name_in(Language::DE));
+        assert_eq!("Korean", Language::KO.full_name_in(Language::EN));
+    }
+
+    #[test]
+    fn supported_builtin_entities_works() {
+        let ko_entities = Language::KO.supported_builtin_entities();
+        assert!(ko_entities.contains(&BuiltinEntityKind::Number));
+        assert!(!ko_entities.contains(&BuiltinEntityKind::MusicTrack));
+
+        let ko_gazetteer_entities = Language::KO.supported_gazetteer_entities();
+        assert!(ko_gazetteer_entities.is_empty());
+
+        let en_grammar_entities = Language::EN.supported_grammar_entities();
+        assert_eq!(GrammarEntityKind::all(), &*en_grammar_entities);
+    }
 }
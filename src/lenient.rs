@@ -0,0 +1,243 @@
+//! Lenient deserialization of the ontology types
+//!
+//! Results produced by a more recent version of the ontology may contain slot value kinds and
+//! builtin entity identifiers which this version does not know. They are rejected by default,
+//! whereas values wrapped in `Lenient` keep the unknown slot values as `SlotValue::Unknown`, and
+//! `LenientBuiltinEntity` keeps the unknown identifiers as `LenientEntityKind::Unknown`. Both
+//! serialize back to the exact same JSON.
+
+use crate::entity::builtin_entity::LenientBuiltinEntity;
+use crate::errors::*;
+use crate::ontology::*;
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer};
+use serde_json::Value;
+use std::ops::Range;
+
+/// Value deserialized leniently, keeping the slot values unknown to this version of the ontology
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Deserializes a value from its JSON, keeping unknown slot values and entity kinds
+pub fn from_str<T>(json: &str) -> Result<T>
+where
+    Lenient<T>: DeserializeOwned,
+{
+    Ok(serde_json::from_str::<Lenient<T>>(json)?.into_inner())
+}
+
+/// Deserializes a value from a JSON value, keeping unknown slot values and entity kinds
+pub fn from_value<T>(value: Value) -> Result<T>
+where
+    Lenient<T>: DeserializeOwned,
+{
+    Ok(serde_json::from_value::<Lenient<T>>(value)?.into_inner())
+}
+
+/// Deserializes a field leniently, see `Lenient`
+pub(crate) fn deserialize_lenient<'de, D, T>(deserializer: D) -> ::std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    Lenient<T>: Deserialize<'de>,
+{
+    Lenient::<T>::deserialize(deserializer).map(Lenient::into_inner)
+}
+
+/// Deserializes a list field leniently, see `Lenient`
+pub(crate) fn deserialize_all_lenient<'de, D, T>(
+    deserializer: D,
+) -> ::std::result::Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    Lenient<T>: Deserialize<'de>,
+{
+    Vec::<Lenient<T>>::deserialize(deserializer)
+        .map(|values| values.into_iter().map(Lenient::into_inner).collect())
+}
+
+impl<'de> Deserialize<'de> for Lenient<SlotValue> {
+    /// Buffers the value in order to look up its kind, which is only needed in lenient mode
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let is_unknown_kind = value
+            .get("kind")
+            .and_then(Value::as_str)
+            .map(|kind| {
+                SlotValueKind::all()
+                    .iter()
+                    .all(|known| known.name() != kind)
+            })
+            .unwrap_or(false);
+        if is_unknown_kind {
+            UnknownSlotValue::deserialize(value).map(SlotValue::Unknown)
+        } else {
+            SlotValue::deserialize(value)
+        }
+        .map(Lenient)
+        .map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<LenientBuiltinEntity> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        LenientBuiltinEntity::deserialize(deserializer).map(Lenient)
+    }
+}
+
+// The definitions below mirror the ones of the ontology, with their slot values deserialized
+// leniently
+
+#[derive(Deserialize)]
+#[serde(remote = "IntentParserResult")]
+struct IntentParserResultDef {
+    input: String,
+    intent: IntentClassifierResult,
+    #[serde(deserialize_with = "deserialize_all_lenient")]
+    slots: Vec<Slot>,
+    #[serde(default, deserialize_with = "deserialize_all_lenient")]
+    alternatives: Vec<IntentParserAlternative>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "IntentParserAlternative")]
+struct IntentParserAlternativeDef {
+    intent: IntentClassifierResult,
+    #[serde(deserialize_with = "deserialize_all_lenient")]
+    slots: Vec<Slot>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Slot", rename_all = "camelCase")]
+struct SlotDef {
+    raw_value: String,
+    #[serde(deserialize_with = "deserialize_lenient")]
+    value: SlotValue,
+    #[serde(default, deserialize_with = "deserialize_all_lenient")]
+    alternatives: Vec<SlotValue>,
+    range: Range<usize>,
+    entity: String,
+    slot_name: String,
+    confidence_score: Option<f32>,
+}
+
+impl<'de> Deserialize<'de> for Lenient<IntentParserResult> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        IntentParserResultDef::deserialize(deserializer).map(Lenient)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<IntentParserAlternative> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        IntentParserAlternativeDef::deserialize(deserializer).map(Lenient)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lenient<Slot> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        SlotDef::deserialize(deserializer).map(Lenient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_unknown_slot_values_round_trip() {
+        // Given
+        let json = r#"{
+            "input": "call me in a fortnight",
+            "intent": {"intentName": "setReminder", "confidenceScore": 0.5},
+            "slots": [{
+                "rawValue": "a fortnight",
+                "value": {"kind": "Fortnight", "count": 1, "precision": "Exact"},
+                "alternatives": [{"kind": "Duration", "years": 0, "quarters": 0, "months": 0,
+                    "weeks": 2, "days": 0, "hours": 0, "minutes": 0, "seconds": 0,
+                    "precision": "Exact"}],
+                "range": {"start": 11, "end": 22},
+                "entity": "snips/fortnight",
+                "slotName": "delay"
+            }],
+            "alternatives": [{
+                "intent": {"intentName": "setTimer", "confidenceScore": 0.25},
+                "slots": [{
+                    "rawValue": "a fortnight",
+                    "value": {"kind": "Fortnight", "count": 1, "precision": "Exact"},
+                    "range": {"start": 11, "end": 22},
+                    "entity": "snips/fortnight",
+                    "slotName": "duration",
+                    "alternatives": []
+                }]
+            }]
+        }"#;
+
+        // When
+        let strict_result = serde_json::from_str::<IntentParserResult>(json);
+        let result: IntentParserResult = from_str(json).unwrap();
+
+        // Then
+        let error = strict_result.unwrap_err().to_string();
+        assert!(error.contains("unknown variant `Fortnight`"), "{}", error);
+        let slot = &result.slots[0];
+        assert_eq!(SlotValueKind::Unknown, slot.value.kind());
+        assert_eq!(SlotValueKind::Duration, slot.alternatives[0].kind());
+        match &slot.value {
+            SlotValue::Unknown(value) => {
+                assert_eq!("Fortnight", value.kind);
+                assert_eq!(Some(&Value::from(1)), value.payload.get("count"));
+            }
+            value => panic!("Unexpected slot value: {:?}", value),
+        }
+        let alternative_slot = &result.alternatives[0].slots[0];
+        assert_eq!(SlotValueKind::Unknown, alternative_slot.value.kind());
+        let expected_json: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(expected_json, serde_json::to_value(&result).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_entity_kinds_round_trip() {
+        // Given
+        let json = r#"{
+            "value": "a fortnight",
+            "range": {"start": 0, "end": 11},
+            "entity": {"kind": "Fortnight", "count": 1},
+            "alternatives": [],
+            "entity_kind": "snips/fortnight"
+        }"#;
+
+        // When
+        let strict_entity = serde_json::from_str::<BuiltinEntity>(json);
+        let entity: LenientBuiltinEntity = from_str(json).unwrap();
+
+        // Then
+        assert!(strict_entity.is_err());
+        assert!(BuiltinEntity::try_from(entity.clone()).is_err());
+        assert_eq!(
+            LenientEntityKind::Unknown("snips/fortnight".to_string()),
+            entity.entity_kind
+        );
+        assert_eq!(None, entity.entity_kind.known());
+        let expected_json: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(expected_json, serde_json::to_value(&entity).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_known_slot_values_are_rejected() {
+        // Given
+        let json = r#"{"kind": "Number", "value": "forty-two"}"#;
+
+        // When
+        let result = from_str::<SlotValue>(json);
+
+        // Then
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("invalid type: string"), "{}", error);
+    }
+}
@@ -1,13 +1,43 @@
 #[macro_use]
 extern crate serde_derive;
 
+#[cfg(feature = "msgpack")]
+pub mod binary;
+mod currency;
+pub mod dataset;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+mod duration;
 pub mod entity;
 pub mod errors;
 pub mod language;
+pub mod lenient;
+mod localization;
 pub mod macros;
+pub mod migration;
 mod ontology;
-pub use entity::builtin_entity::{BuiltinEntity, BuiltinEntityKind, IntoBuiltinEntityKind};
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "python")]
+pub mod python;
+mod range;
+#[cfg(feature = "schemars")]
+pub mod schema;
+mod temperature;
+mod validation;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+pub use currency::{Currency, Money};
+pub use entity::builtin_entity::{
+    BuiltinEntity, BuiltinEntityKind, IntoBuiltinEntityKind, LenientBuiltinEntity,
+    LenientEntityKind,
+};
+pub use entity::custom_entity::{CustomEntityDescriptor, EntityValue};
+pub use entity::entity_ref::EntityRef;
 pub use entity::gazetteer_entity::*;
 pub use entity::grammar_entity::*;
 pub use language::*;
 pub use ontology::*;
+pub use range::RangeUnit;
+pub use temperature::TemperatureUnit;
+pub use validation::{ValidationIssue, ValidationIssueKind};
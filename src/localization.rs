@@ -0,0 +1,279 @@
+//! Translations of the human readable strings of the ontology
+//!
+//! English strings are defined alongside their types and are used as a fallback whenever a
+//! translation is missing here.
+
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::language::Language;
+
+pub(crate) fn builtin_entity_description(
+    entity_kind: BuiltinEntityKind,
+    language: Language,
+) -> Option<&'static str> {
+    use crate::entity::builtin_entity::BuiltinEntityKind::*;
+    use crate::language::Language::*;
+
+    Some(match (entity_kind, language) {
+        (AmountOfMoney, DE) => "Erkennt einen Geldbetrag",
+        (AmountOfMoney, ES) => "Reconoce una cantidad de dinero",
+        (AmountOfMoney, FR) => "Reconnaît un montant d'argent",
+        (AmountOfMoney, IT) => "Riconosce una somma di denaro",
+        (AmountOfMoney, PT_PT) => "Reconhece uma quantia de dinheiro",
+        (AmountOfMoney, PT_BR) => "Reconhece uma quantia de dinheiro",
+        (AmountOfMoney, JA) => "金額にマッチします",
+        (AmountOfMoney, KO) => "금액과 일치합니다",
+
+        (Duration, DE) => "Erkennt eine Zeitdauer",
+        (Duration, ES) => "Reconoce una duración",
+        (Duration, FR) => "Reconnaît une durée",
+        (Duration, IT) => "Riconosce una durata",
+        (Duration, PT_PT) => "Reconhece uma duração",
+        (Duration, PT_BR) => "Reconhece uma duração",
+        (Duration, JA) => "時間の長さにマッチします",
+        (Duration, KO) => "기간과 일치합니다",
+
+        (Number, DE) => "Erkennt eine Kardinalzahl",
+        (Number, ES) => "Reconoce un número cardinal",
+        (Number, FR) => "Reconnaît un nombre cardinal",
+        (Number, IT) => "Riconosce un numero cardinale",
+        (Number, PT_PT) => "Reconhece um número cardinal",
+        (Number, PT_BR) => "Reconhece um número cardinal",
+        (Number, JA) => "基数にマッチします",
+        (Number, KO) => "기수와 일치합니다",
+
+        (Ordinal, DE) => "Erkennt eine Ordinalzahl",
+        (Ordinal, ES) => "Reconoce un número ordinal",
+        (Ordinal, FR) => "Reconnaît un nombre ordinal",
+        (Ordinal, IT) => "Riconosce un numero ordinale",
+        (Ordinal, PT_PT) => "Reconhece um número ordinal",
+        (Ordinal, PT_BR) => "Reconhece um número ordinal",
+        (Ordinal, JA) => "序数にマッチします",
+        (Ordinal, KO) => "서수와 일치합니다",
+
+        (Temperature, DE) => "Erkennt eine Temperatur",
+        (Temperature, ES) => "Reconoce una temperatura",
+        (Temperature, FR) => "Reconnaît une température",
+        (Temperature, IT) => "Riconosce una temperatura",
+        (Temperature, PT_PT) => "Reconhece uma temperatura",
+        (Temperature, PT_BR) => "Reconhece uma temperatura",
+        (Temperature, JA) => "温度にマッチします",
+        (Temperature, KO) => "온도와 일치합니다",
+
+        (Datetime, DE) => {
+            "Erkennt ein Datum, eine Uhrzeit, ein Intervall oder Datum und Uhrzeit zusammen"
+        }
+        (Datetime, ES) => "Reconoce una fecha, una hora, un intervalo o una fecha y hora juntas",
+        (Datetime, FR) => {
+            "Reconnaît une date, une heure, un intervalle ou une date et une heure ensemble"
+        }
+        (Datetime, IT) => {
+            "Riconosce una data, un orario, un intervallo o una data e un orario insieme"
+        }
+        (Datetime, PT_PT) => {
+            "Reconhece uma data, uma hora, um intervalo ou uma data e hora em conjunto"
+        }
+        (Datetime, PT_BR) => {
+            "Reconhece uma data, um horário, um intervalo ou uma data e horário juntos"
+        }
+        (Datetime, JA) => "日付、時刻、期間、または日付と時刻の組み合わせにマッチします",
+        (Datetime, KO) => "날짜, 시간, 구간 또는 날짜와 시간의 조합과 일치합니다",
+
+        (Date, DE) => "Erkennt ein Datum",
+        (Date, ES) => "Reconoce una fecha",
+        (Date, FR) => "Reconnaît une date",
+        (Date, IT) => "Riconosce una data",
+        (Date, PT_PT) => "Reconhece uma data",
+        (Date, PT_BR) => "Reconhece uma data",
+        (Date, JA) => "日付にマッチします",
+        (Date, KO) => "날짜와 일치합니다",
+
+        (Time, DE) => "Erkennt eine Uhrzeit",
+        (Time, ES) => "Reconoce una hora del día",
+        (Time, FR) => "Reconnaît une heure de la journée",
+        (Time, IT) => "Riconosce un orario del giorno",
+        (Time, PT_PT) => "Reconhece uma hora do dia",
+        (Time, PT_BR) => "Reconhece um horário do dia",
+        (Time, JA) => "時刻にマッチします",
+        (Time, KO) => "시각과 일치합니다",
+
+        (DatePeriod, DE) => {
+            "Erkennt einen Zeitraum, der sich über Tage oder größere Einheiten erstreckt"
+        }
+        (DatePeriod, ES) => "Reconoce un periodo de tiempo que abarca días o unidades mayores",
+        (DatePeriod, FR) => {
+            "Reconnaît une période de temps s'étendant sur des jours ou des unités plus grandes"
+        }
+        (DatePeriod, IT) => {
+            "Riconosce un periodo di tempo che si estende su giorni o unità più grandi"
+        }
+        (DatePeriod, PT_PT) => "Reconhece um período de tempo que abrange dias ou unidades maiores",
+        (DatePeriod, PT_BR) => "Reconhece um período de tempo que abrange dias ou unidades maiores",
+        (DatePeriod, JA) => "日単位以上にわたる期間にマッチします",
+        (DatePeriod, KO) => "일 단위 이상에 걸친 기간과 일치합니다",
+
+        (TimePeriod, DE) => {
+            "Erkennt einen Zeitraum, der sich über Stunden oder kleinere Einheiten erstreckt"
+        }
+        (TimePeriod, ES) => "Reconoce un periodo de tiempo que abarca horas o unidades menores",
+        (TimePeriod, FR) => {
+            "Reconnaît une période de temps s'étendant sur des heures ou des unités plus petites"
+        }
+        (TimePeriod, IT) => {
+            "Riconosce un periodo di tempo che si estende su ore o unità più piccole"
+        }
+        (TimePeriod, PT_PT) => {
+            "Reconhece um período de tempo que abrange horas ou unidades menores"
+        }
+        (TimePeriod, PT_BR) => {
+            "Reconhece um período de tempo que abrange horas ou unidades menores"
+        }
+        (TimePeriod, JA) => "時間単位以下にわたる期間にマッチします",
+        (TimePeriod, KO) => "시간 단위 이하에 걸친 기간과 일치합니다",
+
+        (Percentage, DE) => "Erkennt einen Prozentsatz",
+        (Percentage, ES) => "Reconoce un porcentaje",
+        (Percentage, FR) => "Reconnaît un pourcentage",
+        (Percentage, IT) => "Riconosce una percentuale",
+        (Percentage, PT_PT) => "Reconhece uma percentagem",
+        (Percentage, PT_BR) => "Reconhece uma porcentagem",
+        (Percentage, JA) => "パーセンテージにマッチします",
+        (Percentage, KO) => "백분율과 일치합니다",
+
+        (MusicAlbum, DE) => "Erkennt ein Musikalbum",
+        (MusicAlbum, ES) => "Reconoce un álbum de música",
+        (MusicAlbum, FR) => "Reconnaît un album de musique",
+        (MusicAlbum, IT) => "Riconosce un album musicale",
+        (MusicAlbum, PT_PT) => "Reconhece um álbum de música",
+        (MusicAlbum, PT_BR) => "Reconhece um álbum de música",
+        (MusicAlbum, JA) => "音楽アルバムにマッチします",
+        (MusicAlbum, KO) => "음악 앨범과 일치합니다",
+
+        (MusicArtist, DE) => "Erkennt einen Musikkünstler",
+        (MusicArtist, ES) => "Reconoce un artista musical",
+        (MusicArtist, FR) => "Reconnaît un artiste de musique",
+        (MusicArtist, IT) => "Riconosce un artista musicale",
+        (MusicArtist, PT_PT) => "Reconhece um artista musical",
+        (MusicArtist, PT_BR) => "Reconhece um artista musical",
+        (MusicArtist, JA) => "音楽アーティストにマッチします",
+        (MusicArtist, KO) => "음악 아티스트와 일치합니다",
+
+        (MusicTrack, DE) => "Erkennt einen Musiktitel",
+        (MusicTrack, ES) => "Reconoce una canción",
+        (MusicTrack, FR) => "Reconnaît un morceau de musique",
+        (MusicTrack, IT) => "Riconosce un brano musicale",
+        (MusicTrack, PT_PT) => "Reconhece uma faixa de música",
+        (MusicTrack, PT_BR) => "Reconhece uma faixa de música",
+        (MusicTrack, JA) => "楽曲にマッチします",
+        (MusicTrack, KO) => "음악 트랙과 일치합니다",
+
+        (City, DE) => "Erkennt wichtige lokale und internationale Städte",
+        (City, ES) => "Reconoce las principales ciudades locales y del mundo",
+        (City, FR) => "Reconnaît les principales villes locales et mondiales",
+        (City, IT) => "Riconosce le principali città locali e del mondo",
+        (City, PT_PT) => "Reconhece as principais cidades locais e do mundo",
+        (City, PT_BR) => "Reconhece as principais cidades locais e do mundo",
+        (City, JA) => "国内および世界の主要都市にマッチします",
+        (City, KO) => "국내 및 세계 주요 도시와 일치합니다",
+
+        (Country, DE) => "Erkennt Länder auf der ganzen Welt",
+        (Country, ES) => "Reconoce países de todo el mundo",
+        (Country, FR) => "Reconnaît les pays du monde entier",
+        (Country, IT) => "Riconosce i paesi di tutto il mondo",
+        (Country, PT_PT) => "Reconhece países de todo o mundo",
+        (Country, PT_BR) => "Reconhece países de todo o mundo",
+        (Country, JA) => "世界の国々にマッチします",
+        (Country, KO) => "세계 각국과 일치합니다",
+
+        (Region, DE) => "Erkennt lokale Verwaltungsregionen",
+        (Region, ES) => "Reconoce regiones administrativas locales",
+        (Region, FR) => "Reconnaît les régions administratives locales",
+        (Region, IT) => "Riconosce le regioni amministrative locali",
+        (Region, PT_PT) => "Reconhece regiões administrativas locais",
+        (Region, PT_BR) => "Reconhece regiões administrativas locais",
+        (Region, JA) => "国内の行政区域にマッチします",
+        (Region, KO) => "국내 행정 구역과 일치합니다",
+
+        (_, EN) => return None,
+    })
+}
+
+pub(crate) fn language_full_name(
+    language: Language,
+    display_language: Language,
+) -> Option<&'static str> {
+    use crate::language::Language::*;
+
+    Some(match (language, display_language) {
+        (DE, DE) => "Deutsch",
+        (EN, DE) => "Englisch",
+        (ES, DE) => "Spanisch",
+        (FR, DE) => "Französisch",
+        (IT, DE) => "Italienisch",
+        (PT_PT, DE) => "Portugiesisch - Europa",
+        (PT_BR, DE) => "Portugiesisch - Brasilien",
+        (JA, DE) => "Japanisch",
+        (KO, DE) => "Koreanisch",
+
+        (DE, ES) => "Alemán",
+        (EN, ES) => "Inglés",
+        (ES, ES) => "Español",
+        (FR, ES) => "Francés",
+        (IT, ES) => "Italiano",
+        (PT_PT, ES) => "Portugués - Europa",
+        (PT_BR, ES) => "Portugués - Brasil",
+        (JA, ES) => "Japonés",
+        (KO, ES) => "Coreano",
+
+        (DE, FR) => "Allemand",
+        (EN, FR) => "Anglais",
+        (ES, FR) => "Espagnol",
+        (FR, FR) => "Français",
+        (IT, FR) => "Italien",
+        (PT_PT, FR) => "Portugais - Europe",
+        (PT_BR, FR) => "Portugais - Brésil",
+        (JA, FR) => "Japonais",
+        (KO, FR) => "Coréen",
+
+        (DE, IT) => "Tedesco",
+        (EN, IT) => "Inglese",
+        (ES, IT) => "Spagnolo",
+        (FR, IT) => "Francese",
+        (IT, IT) => "Italiano",
+        (PT_PT, IT) => "Portoghese - Europa",
+        (PT_BR, IT) => "Portoghese - Brasile",
+        (JA, IT) => "Giapponese",
+        (KO, IT) => "Coreano",
+
+        (DE, PT_PT) | (DE, PT_BR) => "Alemão",
+        (EN, PT_PT) | (EN, PT_BR) => "Inglês",
+        (ES, PT_PT) | (ES, PT_BR) => "Espanhol",
+        (FR, PT_PT) | (FR, PT_BR) => "Francês",
+        (IT, PT_PT) | (IT, PT_BR) => "Italiano",
+        (PT_PT, PT_PT) | (PT_PT, PT_BR) => "Português - Europa",
+        (PT_BR, PT_PT) | (PT_BR, PT_BR) => "Português - Brasil",
+        (JA, PT_PT) | (JA, PT_BR) => "Japonês",
+        (KO, PT_PT) | (KO, PT_BR) => "Coreano",
+
+        (DE, JA) => "ドイツ語",
+        (EN, JA) => "英語",
+        (ES, JA) => "スペイン語",
+        (FR, JA) => "フランス語",
+        (IT, JA) => "イタリア語",
+        (PT_PT, JA) => "ポルトガル語 - ヨーロッパ",
+        (PT_BR, JA) => "ポルトガル語 - ブラジル",
+        (JA, JA) => "日本語",
+        (KO, JA) => "韓国語",
+
+        (DE, KO) => "독일어",
+        (EN, KO) => "영어",
+        (ES, KO) => "스페인어",
+        (FR, KO) => "프랑스어",
+        (IT, KO) => "이탈리아어",
+        (PT_PT, KO) => "포르투갈어 - 유럽",
+        (PT_BR, KO) => "포르투갈어 - 브라질",
+        (JA, KO) => "일본어",
+        (KO, KO) => "한국어",
+
+        (_, EN) => return None,
+    })
+}
@@ -15,13 +15,13 @@ macro_rules! language_enum {
         }
 
         impl ::std::str::FromStr for Language {
-            type Err=::failure::Error;
+            type Err=$crate::errors::OntologyError;
             fn from_str(s: &str) -> ::std::result::Result<Language, Self::Err> {
                 match &*s.to_uppercase() {
                     $(
                         stringify!($language) => Ok(Language::$language),
                     )*
-                    _ => bail!("Unknown language: {}", s)
+                    _ => Err($crate::errors::OntologyError::UnknownLanguage(s.to_string()))
                 }
             }
         }
@@ -54,13 +54,16 @@ macro_rules! enum_kind {
         }
 
         impl ::std::str::FromStr for $kindname {
-            type Err=String;
+            type Err=$crate::errors::OntologyError;
             fn from_str(s: &str) -> ::std::result::Result<$kindname, Self::Err> {
                 match s {
                     $(
                         stringify!($varname) => Ok($kindname::$varname),
                     )*
-                    _ => Err(format!("{} is not a known {}", s, stringify!($kindname)))
+                    _ => Err($crate::errors::OntologyError::UnknownEntityKind {
+                        kind: stringify!($kindname),
+                        name: s.to_string(),
+                    })
                 }
             }
         }
@@ -85,7 +88,7 @@ macro_rules! enum_kind {
 
 #[macro_export]
 macro_rules! sub_entity_kind {
-    ($kindname:ident, [$($varname:ident),*]) => {
+    ($kindname:ident, $not_a_sub_entity:ident, [$($varname:ident),*]) => {
         #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
         pub enum $kindname {
             $( $varname ),*
@@ -99,13 +102,16 @@ macro_rules! sub_entity_kind {
         }
 
         impl ::std::str::FromStr for $kindname {
-            type Err=String;
+            type Err=$crate::errors::OntologyError;
             fn from_str(s: &str) -> ::std::result::Result<$kindname, Self::Err> {
                 match s {
                     $(
                         stringify!($varname) => Ok($kindname::$varname),
                     )*
-                    _ => Err(format!("{} is not a known {}", s, stringify!($kindname)))
+                    _ => Err($crate::errors::OntologyError::UnknownEntityKind {
+                        kind: stringify!($kindname),
+                        name: s.to_string(),
+                    })
                 }
             }
         }
@@ -131,12 +137,15 @@ macro_rules! sub_entity_kind {
         }
 
         impl $kindname {
-            pub fn from_identifier(identifier: &str) -> Result<Self> {
+            pub fn from_identifier(identifier: &str) -> $crate::errors::OntologyResult<Self> {
+                let builtin_kind = BuiltinEntityKind::from_identifier(identifier)?;
                 $kindname::all()
                     .iter()
-                    .find(|kind| kind.identifier() == identifier)
-                    .map(|k| k.clone())
-                    .ok_or(format_err!("Unknown {} identifier: {}", stringify!($kindname), identifier))
+                    .find(|kind| kind.into_builtin_kind() == builtin_kind)
+                    .cloned()
+                    .ok_or_else(|| {
+                        $crate::errors::OntologyError::$not_a_sub_entity(identifier.to_string())
+                    })
             }
         }
     }
@@ -0,0 +1,495 @@
+//! Versioning and migration of serialized intent parser results
+//!
+//! A result can be serialized in a `Versioned` envelope recording the version of the ontology
+//! which produced it. Results, enveloped or not, are upgraded to the current types by
+//! `migrate_intent_parser_result`, which applies the migrations introduced after the recorded
+//! version, or all of them when the version is unknown.
+//!
+//! Migrations only cover changes of the serialized format: no builtin entity identifier (such as
+//! `snips/datetime`) has ever been renamed, so the `entity` of slots is kept as is.
+
+use crate::errors::*;
+use crate::ontology::IntentParserResult;
+use failure::{bail, format_err, Fail, ResultExt};
+use serde_json::{json, Map, Value};
+use std::fmt;
+use std::str::FromStr;
+
+/// Version of the ontology crate, recorded in the envelopes it produces
+pub const ONTOLOGY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Serialized value along with the version of the ontology which produced it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Versioned<T> {
+    pub ontology_version: String,
+    pub payload: T,
+}
+
+impl<T> Versioned<T> {
+    /// Wraps the value in an envelope recording the current ontology version
+    pub fn new(payload: T) -> Self {
+        Versioned {
+            ontology_version: ONTOLOGY_VERSION.to_string(),
+            payload,
+        }
+    }
+}
+
+/// Version of the ontology, compared by major, minor and patch numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OntologyVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl OntologyVersion {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        OntologyVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn current() -> Self {
+        OntologyVersion::from_str(ONTOLOGY_VERSION).unwrap()
+    }
+}
+
+impl FromStr for OntologyVersion {
+    type Err = failure::Error;
+
+    /// Parses versions such as "0.67.2", ignoring any pre-release or build suffix
+    fn from_str(version: &str) -> Result<Self> {
+        let numbers = version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(u64::from_str)
+            .collect::<::std::result::Result<Vec<_>, _>>()
+            .with_context(|_| format!("Invalid ontology version: {}", version))?;
+        match numbers[..] {
+            [major, minor, patch] => Ok(OntologyVersion::new(major, minor, patch)),
+            _ => bail!("Invalid ontology version: {}", version),
+        }
+    }
+}
+
+impl fmt::Display for OntologyVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Deserialized value, upgraded to the current types
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migrated<T> {
+    pub value: T,
+    /// Version recorded in the envelope of the payload, if any
+    pub source_version: Option<OntologyVersion>,
+    /// Descriptions of the migrations which were applied to the payload
+    pub applied_migrations: Vec<&'static str>,
+}
+
+impl<T> Migrated<T> {
+    /// Returns true when the payload was produced by a more recent version of the ontology, in
+    /// which case fields unknown to this version may have been dropped
+    pub fn is_from_newer_version(&self) -> bool {
+        self.source_version
+            .map(|version| version > OntologyVersion::current())
+            .unwrap_or(false)
+    }
+}
+
+struct Migration {
+    /// Version in which the serialized format changed
+    version: OntologyVersion,
+    description: &'static str,
+    /// Upgrades the JSON of an intent parser result, returning whether it was modified
+    migrate: fn(&mut Map<String, Value>) -> bool,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: OntologyVersion::new(0, 63, 0),
+        description: "replace null slots with an empty list",
+        migrate: replace_null_slots,
+    },
+    Migration {
+        version: OntologyVersion::new(0, 63, 0),
+        description: "replace a missing intent with an empty intent classification",
+        migrate: replace_missing_intent,
+    },
+    Migration {
+        version: OntologyVersion::new(0, 63, 0),
+        description: "locate the range of slots without one in the input",
+        migrate: add_missing_slot_ranges,
+    },
+    Migration {
+        version: OntologyVersion::new(0, 64, 0),
+        description: "rename the intent probability to confidenceScore",
+        migrate: rename_intent_probability,
+    },
+    Migration {
+        version: OntologyVersion::new(0, 67, 0),
+        description: "add missing alternatives to the result and its slots",
+        migrate: add_missing_alternatives,
+    },
+];
+
+/// Deserializes an intent parser result from its JSON, enveloped in a `Versioned` or not, after
+/// having upgraded it to the current types
+///
+/// Payloads produced by a newer version of the ontology are deserialized on a best-effort basis,
+/// and an error mentioning their version is returned when it fails.
+pub fn migrate_intent_parser_result(json: &str) -> Result<Migrated<IntentParserResult>> {
+    let value: Value = serde_json::from_str(json)?;
+    let (source_version, mut payload) = match value {
+        Value::Object(mut object) if object.contains_key("ontologyVersion") => {
+            let version = object
+                .get("ontologyVersion")
+                .and_then(Value::as_str)
+                .ok_or_else(|| format_err!("The ontology version must be a string"))?;
+            let version = OntologyVersion::from_str(version)?;
+            let payload = object
+                .remove("payload")
+                .ok_or_else(|| format_err!("Missing payload in versioned envelope"))?;
+            (Some(version), payload)
+        }
+        value => (None, value),
+    };
+    let object = payload
+        .as_object_mut()
+        .ok_or_else(|| format_err!("An intent parser result must be a JSON object"))?;
+
+    let applied_migrations = MIGRATIONS
+        .iter()
+        .filter(|migration| source_version.is_none_or(|version| version < migration.version))
+        .filter(|migration| (migration.migrate)(object))
+        .map(|migration| migration.description)
+        .collect();
+
+    let value = match (serde_json::from_value(payload), source_version) {
+        (Ok(value), _) => value,
+        (Err(error), Some(version)) if version > OntologyVersion::current() => {
+            return Err(error
+                .context(format!(
+                    "The payload was produced by ontology {}, which is newer than {}",
+                    version, ONTOLOGY_VERSION
+                ))
+                .into());
+        }
+        (Err(error), _) => return Err(error.into()),
+    };
+    Ok(Migrated {
+        value,
+        source_version,
+        applied_migrations,
+    })
+}
+
+/// Returns the slots of the result and of its alternatives
+fn slot_lists(result: &mut Map<String, Value>) -> Vec<&mut Value> {
+    let mut slot_lists = vec![];
+    for (key, value) in result.iter_mut() {
+        match &**key {
+            "slots" => slot_lists.push(value),
+            "alternatives" => {
+                let alternatives = value.as_array_mut().into_iter().flatten();
+                slot_lists.extend(alternatives.filter_map(|a| a.get_mut("slots")));
+            }
+            _ => (),
+        }
+    }
+    slot_lists
+}
+
+fn replace_null_slots(result: &mut Map<String, Value>) -> bool {
+    match result.get_mut("slots") {
+        Some(slots) if slots.is_null() => {
+            *slots = Value::Array(vec![]);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Legacy results used a null intent when none was found, which is now an intent classification
+/// without name and with a zero confidence score
+fn replace_missing_intent(result: &mut Map<String, Value>) -> bool {
+    match result.get("intent") {
+        Some(intent) if !intent.is_null() => false,
+        _ => {
+            let intent = json!({"intentName": null, "probability": 0.0});
+            result.insert("intent".to_string(), intent);
+            true
+        }
+    }
+}
+
+/// Legacy slots could have a null range, which is replaced by the character range of the first
+/// occurrence of the raw value in the input
+///
+/// Slots whose raw value cannot be found are left untouched and fail to deserialize.
+fn add_missing_slot_ranges(result: &mut Map<String, Value>) -> bool {
+    let input = match result.get("input").and_then(Value::as_str) {
+        Some(input) => input.to_string(),
+        None => return false,
+    };
+    let mut modified = false;
+    for slots in slot_lists(result) {
+        let slots = slots.as_array_mut().into_iter().flatten();
+        for slot in slots.filter_map(Value::as_object_mut) {
+            if slot.get("range").is_some_and(|range| !range.is_null()) {
+                continue;
+            }
+            let range = slot
+                .get("rawValue")
+                .and_then(Value::as_str)
+                .and_then(|raw_value| char_range(&input, raw_value));
+            if let Some((start, end)) = range {
+                slot.insert("range".to_string(), json!({"start": start, "end": end}));
+                modified = true;
+            }
+        }
+    }
+    modified
+}
+
+fn char_range(input: &str, raw_value: &str) -> Option<(usize, usize)> {
+    let byte_start = input.find(raw_value)?;
+    let start = input[..byte_start].chars().count();
+    Some((start, start + raw_value.chars().count()))
+}
+
+fn rename_intent_probability(result: &mut Map<String, Value>) -> bool {
+    let intent = match result.get_mut("intent").and_then(Value::as_object_mut) {
+        Some(intent) => intent,
+        None => return false,
+    };
+    if intent.contains_key("confidenceScore") {
+        return false;
+    }
+    match intent.remove("probability") {
+        Some(probability) => {
+            intent.insert("confidenceScore".to_string(), probability);
+            true
+        }
+        None => false,
+    }
+}
+
+fn add_missing_alternatives(result: &mut Map<String, Value>) -> bool {
+    let mut modified = false;
+    if !result.contains_key("alternatives") {
+        result.insert("alternatives".to_string(), Value::Array(vec![]));
+        modified = true;
+    }
+    for slots in slot_lists(result) {
+        let slots = slots.as_array_mut().into_iter().flatten();
+        for slot in slots.filter_map(Value::as_object_mut) {
+            if !slot.contains_key("alternatives") {
+                slot.insert("alternatives".to_string(), Value::Array(vec![]));
+                modified = true;
+            }
+        }
+    }
+    modified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::*;
+
+    #[test]
+    fn test_versioned_result_round_trip() {
+        // Given
+        let result = IntentParserResult {
+            input: "hello".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("greeting".to_string()),
+                confidence_score: 0.5,
+            },
+            slots: vec![],
+            alternatives: vec![],
+        };
+        let json = serde_json::to_string(&Versioned::new(result.clone())).unwrap();
+
+        // When
+        let migrated = migrate_intent_parser_result(&json).unwrap();
+
+        // Then
+        assert!(json.starts_with(&format!(r#"{{"ontologyVersion":"{}""#, ONTOLOGY_VERSION)));
+        assert_eq!(result, migrated.value);
+        assert_eq!(Some(OntologyVersion::current()), migrated.source_version);
+        assert!(migrated.applied_migrations.is_empty());
+        assert!(!migrated.is_from_newer_version());
+    }
+
+    #[test]
+    fn test_migrate_legacy_result() {
+        // Given
+        let json = r#"{
+            "ontologyVersion": "0.62.0",
+            "payload": {
+                "input": "turn on the light",
+                "intent": {"intentName": "turnLightOn", "probability": 0.8},
+                "slots": null
+            }
+        }"#;
+
+        // When
+        let migrated = migrate_intent_parser_result(json).unwrap();
+
+        // Then
+        let expected_result = IntentParserResult {
+            input: "turn on the light".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("turnLightOn".to_string()),
+                confidence_score: 0.8,
+            },
+            slots: vec![],
+            alternatives: vec![],
+        };
+        assert_eq!(expected_result, migrated.value);
+        assert_eq!(3, migrated.applied_migrations.len());
+    }
+
+    #[test]
+    fn test_migrate_legacy_result_without_intent_nor_slot_range() {
+        // Given
+        let json = r#"{
+            "ontologyVersion": "0.62.0",
+            "payload": {
+                "input": "weather in Zürich",
+                "intent": null,
+                "slots": [{
+                    "rawValue": "Zürich",
+                    "value": {"kind": "City", "value": "Zürich"},
+                    "range": null,
+                    "entity": "snips/city",
+                    "slotName": "location"
+                }]
+            }
+        }"#;
+
+        // When
+        let migrated = migrate_intent_parser_result(json).unwrap();
+
+        // Then
+        let expected_intent = IntentClassifierResult {
+            intent_name: None,
+            confidence_score: 0.0,
+        };
+        assert_eq!(expected_intent, migrated.value.intent);
+        assert_eq!(11..17, migrated.value.slots[0].range);
+        assert_eq!("snips/city", migrated.value.slots[0].entity);
+        assert_eq!(
+            vec![
+                "replace a missing intent with an empty intent classification",
+                "locate the range of slots without one in the input",
+                "rename the intent probability to confidenceScore",
+                "add missing alternatives to the result and its slots",
+            ],
+            migrated.applied_migrations
+        );
+    }
+
+    #[test]
+    fn test_slots_without_range_nor_raw_value_in_input_are_rejected() {
+        // Given
+        let json = r#"{
+            "input": "weather in Paris",
+            "intent": {"intentName": "searchWeather", "probability": 0.8},
+            "slots": [{
+                "rawValue": "London",
+                "value": {"kind": "City", "value": "London"},
+                "entity": "snips/city",
+                "slotName": "location"
+            }]
+        }"#;
+
+        // When
+        let result = migrate_intent_parser_result(json);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_migrate_unversioned_result_without_alternatives() {
+        // Given
+        let json = r#"{
+            "input": "Paris",
+            "intent": {"intentName": "searchWeather", "confidenceScore": 0.8},
+            "slots": [{
+                "rawValue": "Paris",
+                "value": {"kind": "City", "value": "Paris"},
+                "range": {"start": 0, "end": 5},
+                "entity": "snips/city",
+                "slotName": "location"
+            }]
+        }"#;
+
+        // When
+        let migrated = migrate_intent_parser_result(json).unwrap();
+
+        // Then
+        assert_eq!(None, migrated.source_version);
+        assert_eq!(
+            vec!["add missing alternatives to the result and its slots"],
+            migrated.applied_migrations
+        );
+        assert_eq!(1, migrated.value.slots.len());
+    }
+
+    #[test]
+    fn test_newer_results_are_reported() {
+        // Given
+        let newer_version = OntologyVersion {
+            major: OntologyVersion::current().major + 1,
+            ..OntologyVersion::current()
+        };
+        let json = format!(
+            r#"{{
+                "ontologyVersion": "{}",
+                "payload": {{
+                    "input": "hello",
+                    "intent": {{"intentName": null, "confidenceScore": 0.5}},
+                    "slots": [],
+                    "alternatives": [],
+                    "someNewField": true
+                }}
+            }}"#,
+            newer_version
+        );
+        let invalid_json = json.replace(r#""slots": [],"#, r#""slots": 42,"#);
+
+        // When
+        let migrated = migrate_intent_parser_result(&json).unwrap();
+        let error = migrate_intent_parser_result(&invalid_json).unwrap_err();
+
+        // Then
+        assert!(migrated.is_from_newer_version());
+        assert!(error.to_string().contains(&newer_version.to_string()));
+    }
+
+    #[test]
+    fn test_parse_ontology_version() {
+        assert_eq!(
+            OntologyVersion::new(0, 67, 2),
+            OntologyVersion::from_str("0.67.2").unwrap()
+        );
+        assert_eq!(
+            OntologyVersion::new(1, 0, 0),
+            OntologyVersion::from_str("1.0.0-rc.1").unwrap()
+        );
+        assert!(OntologyVersion::from_str("0.67").is_err());
+        assert!(OntologyVersion::from_str("latest").is_err());
+        assert!(OntologyVersion::new(0, 65, 0) < OntologyVersion::new(0, 67, 1));
+    }
+}
@@ -1,6 +1,10 @@
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::{Map, Value};
 use std::ops::Range;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IntentParserResult {
     pub input: String,
     pub intent: IntentClassifierResult,
@@ -10,12 +14,14 @@ pub struct IntentParserResult {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IntentParserAlternative {
     pub intent: IntentClassifierResult,
     pub slots: Vec<Slot>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct IntentClassifierResult {
     pub intent_name: Option<String>,
@@ -23,12 +29,14 @@ pub struct IntentClassifierResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Slot {
     pub raw_value: String,
     pub value: SlotValue,
     #[serde(default)]
     pub alternatives: Vec<SlotValue>,
+    /// Char range of the slot in the input, see `RangeUnit` to convert it
     pub range: Range<usize>,
     pub entity: String,
     pub slot_name: String,
@@ -72,7 +80,8 @@ impl Slot {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "kind")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", remote = "Self")]
 pub enum SlotValue {
     Custom(StringValue),
     Number(NumberValue),
@@ -89,10 +98,105 @@ pub enum SlotValue {
     City(StringValue),
     Country(StringValue),
     Region(StringValue),
+    /// Value of a kind unknown to this version of the ontology, only deserialized leniently
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown(UnknownSlotValue),
+}
+
+/// Kind of a `SlotValue`, named after the "kind" tag of its serialized form
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SlotValueKind {
+    Custom,
+    Number,
+    Ordinal,
+    Percentage,
+    InstantTime,
+    TimeInterval,
+    AmountOfMoney,
+    Temperature,
+    Duration,
+    MusicAlbum,
+    MusicArtist,
+    MusicTrack,
+    City,
+    Country,
+    Region,
+    Unknown,
+}
+
+impl SlotValueKind {
+    /// Returns the kinds known to this version of the ontology
+    pub fn all() -> &'static [SlotValueKind] {
+        static ALL: &[SlotValueKind] = &[
+            SlotValueKind::Custom,
+            SlotValueKind::Number,
+            SlotValueKind::Ordinal,
+            SlotValueKind::Percentage,
+            SlotValueKind::InstantTime,
+            SlotValueKind::TimeInterval,
+            SlotValueKind::AmountOfMoney,
+            SlotValueKind::Temperature,
+            SlotValueKind::Duration,
+            SlotValueKind::MusicAlbum,
+            SlotValueKind::MusicArtist,
+            SlotValueKind::MusicTrack,
+            SlotValueKind::City,
+            SlotValueKind::Country,
+            SlotValueKind::Region,
+        ];
+        ALL
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            SlotValueKind::Custom => "Custom",
+            SlotValueKind::Number => "Number",
+            SlotValueKind::Ordinal => "Ordinal",
+            SlotValueKind::Percentage => "Percentage",
+            SlotValueKind::InstantTime => "InstantTime",
+            SlotValueKind::TimeInterval => "TimeInterval",
+            SlotValueKind::AmountOfMoney => "AmountOfMoney",
+            SlotValueKind::Temperature => "Temperature",
+            SlotValueKind::Duration => "Duration",
+            SlotValueKind::MusicAlbum => "MusicAlbum",
+            SlotValueKind::MusicArtist => "MusicArtist",
+            SlotValueKind::MusicTrack => "MusicTrack",
+            SlotValueKind::City => "City",
+            SlotValueKind::Country => "Country",
+            SlotValueKind::Region => "Region",
+            SlotValueKind::Unknown => "Unknown",
+        }
+    }
+}
+
+impl SlotValue {
+    pub fn kind(&self) -> SlotValueKind {
+        match self {
+            SlotValue::Custom(_) => SlotValueKind::Custom,
+            SlotValue::Number(_) => SlotValueKind::Number,
+            SlotValue::Ordinal(_) => SlotValueKind::Ordinal,
+            SlotValue::Percentage(_) => SlotValueKind::Percentage,
+            SlotValue::InstantTime(_) => SlotValueKind::InstantTime,
+            SlotValue::TimeInterval(_) => SlotValueKind::TimeInterval,
+            SlotValue::AmountOfMoney(_) => SlotValueKind::AmountOfMoney,
+            SlotValue::Temperature(_) => SlotValueKind::Temperature,
+            SlotValue::Duration(_) => SlotValueKind::Duration,
+            SlotValue::MusicAlbum(_) => SlotValueKind::MusicAlbum,
+            SlotValue::MusicArtist(_) => SlotValueKind::MusicArtist,
+            SlotValue::MusicTrack(_) => SlotValueKind::MusicTrack,
+            SlotValue::City(_) => SlotValueKind::City,
+            SlotValue::Country(_) => SlotValueKind::Country,
+            SlotValue::Region(_) => SlotValueKind::Region,
+            SlotValue::Unknown(_) => SlotValueKind::Unknown,
+        }
+    }
 }
 
 /// This struct is required in order to use serde Internally tagged enum representation
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StringValue {
     pub value: String,
 }
@@ -111,35 +215,106 @@ impl From<&'static str> for StringValue {
     }
 }
 
+/// Slot value of a kind unknown to this version of the ontology, kept as raw JSON
+///
+/// Slot values are only deserialized as such through the `lenient` module, and serialize back
+/// to their original JSON.
+#[derive(Clone, PartialEq, Debug)]
+pub struct UnknownSlotValue {
+    /// The "kind" tag of the value
+    pub kind: String,
+    /// The other fields of the value
+    pub payload: Map<String, Value>,
+}
+
+// The implementations derived with `remote = "Self"` are inherent functions, which are wrapped
+// here to handle unknown slot values
+impl Serialize for SlotValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        match self {
+            SlotValue::Unknown(value) => value.serialize(serializer),
+            _ => SlotValue::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SlotValue {
+    /// Rejects the kinds unknown to this version of the ontology, see `lenient::Lenient` to keep
+    /// them as `SlotValue::Unknown`
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        SlotValue::deserialize(deserializer)
+    }
+}
+
+impl Serialize for UnknownSlotValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        let fields = self.payload.iter().filter(|(key, _)| *key != "kind");
+        let mut map = serializer.serialize_map(Some(1 + fields.clone().count()))?;
+        map.serialize_entry("kind", &self.kind)?;
+        for (key, value) in fields {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownSlotValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        let mut payload = Map::deserialize(deserializer)?;
+        match payload.remove("kind") {
+            Some(Value::String(kind)) => Ok(UnknownSlotValue { kind, payload }),
+            _ => Err(de::Error::missing_field("kind")),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct NumberValue {
     pub value: f64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OrdinalValue {
     pub value: i64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PercentageValue {
     pub value: f64,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InstantTimeValue {
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(regex(pattern = r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} [+-]\d{2}:\d{2}$"))
+    )]
     pub value: String,
     pub grain: Grain,
     pub precision: Precision,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TimeIntervalValue {
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(regex(pattern = r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} [+-]\d{2}:\d{2}$"))
+    )]
     pub from: Option<String>,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(regex(pattern = r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} [+-]\d{2}:\d{2}$"))
+    )]
     pub to: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AmountOfMoneyValue {
     pub value: f32,
     pub precision: Precision,
@@ -147,12 +322,14 @@ pub struct AmountOfMoneyValue {
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TemperatureValue {
     pub value: f32,
     pub unit: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DurationValue {
     pub years: i64,
     pub quarters: i64,
@@ -166,6 +343,7 @@ pub struct DurationValue {
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Grain {
     Year = 0,
     Quarter = 1,
@@ -178,6 +356,7 @@ pub enum Grain {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Precision {
     Approximate,
     Exact,
@@ -189,6 +368,30 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_slot_value_kind_matches_serialized_kind() {
+        // Given
+        let values = vec![
+            SlotValue::Custom("foo".into()),
+            SlotValue::Percentage(PercentageValue { value: 20. }),
+            SlotValue::TimeInterval(TimeIntervalValue {
+                from: None,
+                to: None,
+            }),
+            SlotValue::MusicTrack("Harder Better Faster Stronger".into()),
+        ];
+
+        for value in values {
+            // When
+            let kind = value.kind();
+            let json_value = serde_json::to_value(&value).unwrap();
+
+            // Then
+            assert_eq!(json_value["kind"], kind.name());
+            assert_eq!(json_value["kind"], serde_json::to_value(kind).unwrap());
+        }
+    }
+
     #[test]
     fn test_deserializing_with_default_alternatives() {
         // Given
@@ -0,0 +1,545 @@
+//! Protocol Buffers representation of the ontology
+//!
+//! The ontology types convert into the messages of `proto` with `From`, and back with `TryFrom`,
+//! which fails on missing messages, unspecified enum values and unknown entity identifiers.
+//! Builtin entities of unknown kinds are converted back as `LenientBuiltinEntity`.
+
+pub mod proto;
+
+use crate::entity::builtin_entity::{
+    BuiltinEntity, BuiltinEntityKind, LenientBuiltinEntity, LenientEntityKind,
+};
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::*;
+use failure::{bail, format_err};
+use std::convert::{TryFrom, TryInto};
+use std::ops::Range;
+
+fn required<T>(message: Option<T>, name: &str) -> Result<T> {
+    message.ok_or_else(|| format_err!("Missing {} in protobuf message", name))
+}
+
+fn try_from_all<P, T>(messages: Vec<P>) -> Result<Vec<T>>
+where
+    T: TryFrom<P, Error = failure::Error>,
+{
+    messages.into_iter().map(T::try_from).collect()
+}
+
+impl From<IntentParserResult> for proto::IntentParserResult {
+    fn from(result: IntentParserResult) -> Self {
+        Self {
+            input: result.input,
+            intent: Some(result.intent.into()),
+            slots: result.slots.into_iter().map(Into::into).collect(),
+            alternatives: result.alternatives.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::IntentParserResult> for IntentParserResult {
+    type Error = failure::Error;
+
+    fn try_from(result: proto::IntentParserResult) -> Result<Self> {
+        Ok(Self {
+            input: result.input,
+            intent: required(result.intent, "intent")?.into(),
+            slots: try_from_all(result.slots)?,
+            alternatives: try_from_all(result.alternatives)?,
+        })
+    }
+}
+
+impl From<IntentParserAlternative> for proto::IntentParserAlternative {
+    fn from(alternative: IntentParserAlternative) -> Self {
+        Self {
+            intent: Some(alternative.intent.into()),
+            slots: alternative.slots.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::IntentParserAlternative> for IntentParserAlternative {
+    type Error = failure::Error;
+
+    fn try_from(alternative: proto::IntentParserAlternative) -> Result<Self> {
+        Ok(Self {
+            intent: required(alternative.intent, "intent")?.into(),
+            slots: try_from_all(alternative.slots)?,
+        })
+    }
+}
+
+impl From<IntentClassifierResult> for proto::IntentClassifierResult {
+    fn from(result: IntentClassifierResult) -> Self {
+        Self {
+            intent_name: result.intent_name,
+            confidence_score: result.confidence_score,
+        }
+    }
+}
+
+impl From<proto::IntentClassifierResult> for IntentClassifierResult {
+    fn from(result: proto::IntentClassifierResult) -> Self {
+        Self {
+            intent_name: result.intent_name,
+            confidence_score: result.confidence_score,
+        }
+    }
+}
+
+impl From<Range<usize>> for proto::Range {
+    fn from(range: Range<usize>) -> Self {
+        Self {
+            start: range.start as u64,
+            end: range.end as u64,
+        }
+    }
+}
+
+fn range(range: Option<proto::Range>) -> Result<Range<usize>> {
+    let range = required(range, "range")?;
+    Ok(usize::try_from(range.start)?..usize::try_from(range.end)?)
+}
+
+impl From<Slot> for proto::Slot {
+    fn from(slot: Slot) -> Self {
+        Self {
+            raw_value: slot.raw_value,
+            value: Some(slot.value.into()),
+            alternatives: slot.alternatives.into_iter().map(Into::into).collect(),
+            range: Some(slot.range.into()),
+            entity: slot.entity,
+            slot_name: slot.slot_name,
+            confidence_score: slot.confidence_score,
+        }
+    }
+}
+
+impl TryFrom<proto::Slot> for Slot {
+    type Error = failure::Error;
+
+    fn try_from(slot: proto::Slot) -> Result<Self> {
+        Ok(Self {
+            raw_value: slot.raw_value,
+            value: required(slot.value, "value")?.try_into()?,
+            alternatives: try_from_all(slot.alternatives)?,
+            range: range(slot.range)?,
+            entity: slot.entity,
+            slot_name: slot.slot_name,
+            confidence_score: slot.confidence_score,
+        })
+    }
+}
+
+impl From<BuiltinEntity> for proto::BuiltinEntity {
+    fn from(entity: BuiltinEntity) -> Self {
+        Self {
+            value: entity.value,
+            range: Some(entity.range.into()),
+            entity: Some(entity.entity.into()),
+            alternatives: entity.alternatives.into_iter().map(Into::into).collect(),
+            entity_kind: entity.entity_kind.identifier().to_string(),
+        }
+    }
+}
+
+impl TryFrom<proto::BuiltinEntity> for BuiltinEntity {
+    type Error = failure::Error;
+
+    fn try_from(entity: proto::BuiltinEntity) -> Result<Self> {
+        Ok(Self {
+            value: entity.value,
+            range: range(entity.range)?,
+            entity: required(entity.entity, "entity")?.try_into()?,
+            alternatives: try_from_all(entity.alternatives)?,
+            entity_kind: BuiltinEntityKind::from_identifier(&entity.entity_kind)?,
+        })
+    }
+}
+
+impl From<LenientBuiltinEntity> for proto::BuiltinEntity {
+    fn from(entity: LenientBuiltinEntity) -> Self {
+        Self {
+            value: entity.value,
+            range: Some(entity.range.into()),
+            entity: Some(entity.entity.into()),
+            alternatives: entity.alternatives.into_iter().map(Into::into).collect(),
+            entity_kind: entity.entity_kind.identifier().to_string(),
+        }
+    }
+}
+
+impl TryFrom<proto::BuiltinEntity> for LenientBuiltinEntity {
+    type Error = failure::Error;
+
+    fn try_from(entity: proto::BuiltinEntity) -> Result<Self> {
+        Ok(Self {
+            value: entity.value,
+            range: range(entity.range)?,
+            entity: required(entity.entity, "entity")?.try_into()?,
+            alternatives: try_from_all(entity.alternatives)?,
+            entity_kind: LenientEntityKind::from_identifier(&entity.entity_kind),
+        })
+    }
+}
+
+impl From<SlotValue> for proto::SlotValue {
+    fn from(value: SlotValue) -> Self {
+        use self::proto::slot_value::Value;
+
+        let value = match value {
+            SlotValue::Custom(v) => Value::Custom(v.into()),
+            SlotValue::Number(v) => Value::Number(proto::NumberValue { value: v.value }),
+            SlotValue::Ordinal(v) => Value::Ordinal(proto::OrdinalValue { value: v.value }),
+            SlotValue::Percentage(v) => {
+                Value::Percentage(proto::PercentageValue { value: v.value })
+            }
+            SlotValue::InstantTime(v) => Value::InstantTime(proto::InstantTimeValue {
+                value: v.value,
+                grain: proto::Grain::from(v.grain) as i32,
+                precision: proto::Precision::from(v.precision) as i32,
+            }),
+            SlotValue::TimeInterval(v) => Value::TimeInterval(proto::TimeIntervalValue {
+                from: v.from,
+                to: v.to,
+            }),
+            SlotValue::AmountOfMoney(v) => Value::AmountOfMoney(proto::AmountOfMoneyValue {
+                value: v.value,
+                precision: proto::Precision::from(v.precision) as i32,
+                unit: v.unit,
+            }),
+            SlotValue::Temperature(v) => Value::Temperature(proto::TemperatureValue {
+                value: v.value,
+                unit: v.unit,
+            }),
+            SlotValue::Duration(v) => Value::Duration(proto::DurationValue {
+                years: v.years,
+                quarters: v.quarters,
+                months: v.months,
+                weeks: v.weeks,
+                days: v.days,
+                hours: v.hours,
+                minutes: v.minutes,
+                seconds: v.seconds,
+                precision: proto::Precision::from(v.precision) as i32,
+            }),
+            SlotValue::MusicAlbum(v) => Value::MusicAlbum(v.into()),
+            SlotValue::MusicArtist(v) => Value::MusicArtist(v.into()),
+            SlotValue::MusicTrack(v) => Value::MusicTrack(v.into()),
+            SlotValue::City(v) => Value::City(v.into()),
+            SlotValue::Country(v) => Value::Country(v.into()),
+            SlotValue::Region(v) => Value::Region(v.into()),
+            SlotValue::Unknown(v) => Value::Unknown(proto::UnknownValue {
+                kind: v.kind,
+                payload: serde_json::Value::Object(v.payload).to_string(),
+            }),
+        };
+        Self { value: Some(value) }
+    }
+}
+
+impl TryFrom<proto::SlotValue> for SlotValue {
+    type Error = failure::Error;
+
+    fn try_from(value: proto::SlotValue) -> Result<Self> {
+        use self::proto::slot_value::Value;
+
+        Ok(match required(value.value, "slot value")? {
+            Value::Custom(v) => SlotValue::Custom(v.into()),
+            Value::Number(v) => SlotValue::Number(NumberValue { value: v.value }),
+            Value::Ordinal(v) => SlotValue::Ordinal(OrdinalValue { value: v.value }),
+            Value::Percentage(v) => SlotValue::Percentage(PercentageValue { value: v.value }),
+            Value::InstantTime(v) => SlotValue::InstantTime(InstantTimeValue {
+                value: v.value,
+                grain: proto::Grain::try_from(v.grain)?.try_into()?,
+                precision: proto::Precision::try_from(v.precision)?.try_into()?,
+            }),
+            Value::TimeInterval(v) => SlotValue::TimeInterval(TimeIntervalValue {
+                from: v.from,
+                to: v.to,
+            }),
+            Value::AmountOfMoney(v) => SlotValue::AmountOfMoney(AmountOfMoneyValue {
+                value: v.value,
+                precision: proto::Precision::try_from(v.precision)?.try_into()?,
+                unit: v.unit,
+            }),
+            Value::Temperature(v) => SlotValue::Temperature(TemperatureValue {
+                value: v.value,
+                unit: v.unit,
+            }),
+            Value::Duration(v) => SlotValue::Duration(DurationValue {
+                years: v.years,
+                quarters: v.quarters,
+                months: v.months,
+                weeks: v.weeks,
+                days: v.days,
+                hours: v.hours,
+                minutes: v.minutes,
+                seconds: v.seconds,
+                precision: proto::Precision::try_from(v.precision)?.try_into()?,
+            }),
+            Value::MusicAlbum(v) => SlotValue::MusicAlbum(v.into()),
+            Value::MusicArtist(v) => SlotValue::MusicArtist(v.into()),
+            Value::MusicTrack(v) => SlotValue::MusicTrack(v.into()),
+            Value::City(v) => SlotValue::City(v.into()),
+            Value::Country(v) => SlotValue::Country(v.into()),
+            Value::Region(v) => SlotValue::Region(v.into()),
+            Value::Unknown(v) => SlotValue::Unknown(UnknownSlotValue {
+                kind: v.kind,
+                payload: serde_json::from_str(&v.payload)?,
+            }),
+        })
+    }
+}
+
+impl From<StringValue> for proto::StringValue {
+    fn from(value: StringValue) -> Self {
+        Self { value: value.value }
+    }
+}
+
+impl From<proto::StringValue> for StringValue {
+    fn from(value: proto::StringValue) -> Self {
+        Self { value: value.value }
+    }
+}
+
+impl From<Grain> for proto::Grain {
+    fn from(grain: Grain) -> Self {
+        match grain {
+            Grain::Year => proto::Grain::Year,
+            Grain::Quarter => proto::Grain::Quarter,
+            Grain::Month => proto::Grain::Month,
+            Grain::Week => proto::Grain::Week,
+            Grain::Day => proto::Grain::Day,
+            Grain::Hour => proto::Grain::Hour,
+            Grain::Minute => proto::Grain::Minute,
+            Grain::Second => proto::Grain::Second,
+        }
+    }
+}
+
+impl TryFrom<proto::Grain> for Grain {
+    type Error = failure::Error;
+
+    fn try_from(grain: proto::Grain) -> Result<Self> {
+        Ok(match grain {
+            proto::Grain::Unspecified => bail!("Unspecified grain in protobuf message"),
+            proto::Grain::Year => Grain::Year,
+            proto::Grain::Quarter => Grain::Quarter,
+            proto::Grain::Month => Grain::Month,
+            proto::Grain::Week => Grain::Week,
+            proto::Grain::Day => Grain::Day,
+            proto::Grain::Hour => Grain::Hour,
+            proto::Grain::Minute => Grain::Minute,
+            proto::Grain::Second => Grain::Second,
+        })
+    }
+}
+
+impl From<Precision> for proto::Precision {
+    fn from(precision: Precision) -> Self {
+        match precision {
+            Precision::Approximate => proto::Precision::Approximate,
+            Precision::Exact => proto::Precision::Exact,
+        }
+    }
+}
+
+impl TryFrom<proto::Precision> for Precision {
+    type Error = failure::Error;
+
+    fn try_from(precision: proto::Precision) -> Result<Self> {
+        Ok(match precision {
+            proto::Precision::Unspecified => bail!("Unspecified precision in protobuf message"),
+            proto::Precision::Approximate => Precision::Approximate,
+            proto::Precision::Exact => Precision::Exact,
+        })
+    }
+}
+
+impl From<Language> for proto::Language {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::DE => proto::Language::De,
+            Language::EN => proto::Language::En,
+            Language::ES => proto::Language::Es,
+            Language::FR => proto::Language::Fr,
+            Language::IT => proto::Language::It,
+            Language::PT_PT => proto::Language::PtPt,
+            Language::PT_BR => proto::Language::PtBr,
+            Language::JA => proto::Language::Ja,
+            Language::KO => proto::Language::Ko,
+        }
+    }
+}
+
+impl TryFrom<proto::Language> for Language {
+    type Error = failure::Error;
+
+    fn try_from(language: proto::Language) -> Result<Self> {
+        Ok(match language {
+            proto::Language::Unspecified => bail!("Unspecified language in protobuf message"),
+            proto::Language::De => Language::DE,
+            proto::Language::En => Language::EN,
+            proto::Language::Es => Language::ES,
+            proto::Language::Fr => Language::FR,
+            proto::Language::It => Language::IT,
+            proto::Language::PtPt => Language::PT_PT,
+            proto::Language::PtBr => Language::PT_BR,
+            proto::Language::Ja => Language::JA,
+            proto::Language::Ko => Language::KO,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    /// Converts the value to protobuf, encodes and decodes it, and converts it back
+    fn protobuf_round_trip<T, P>(value: T) -> T
+    where
+        P: From<T> + Message + Default,
+        T: TryFrom<P, Error = failure::Error>,
+    {
+        let bytes = P::from(value).encode_to_vec();
+        T::try_from(P::decode(&*bytes).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_intent_parser_result_round_trip() {
+        // Given
+        let json = r#"{
+            "input": "turn the heat to 21 degrees for 2 hours",
+            "intent": {"intentName": "SetTemperature", "confidenceScore": 0.8},
+            "slots": [
+                {
+                    "rawValue": "21 degrees",
+                    "value": {"kind": "Temperature", "value": 21.0, "unit": "degree"},
+                    "alternatives": [{"kind": "Number", "value": 21.0}],
+                    "range": {"start": 17, "end": 27},
+                    "entity": "snips/temperature",
+                    "slotName": "temperature",
+                    "confidenceScore": 0.9
+                },
+                {
+                    "rawValue": "2 hours",
+                    "value": {"kind": "Custom", "value": "two hours"},
+                    "alternatives": [],
+                    "range": {"start": 32, "end": 39},
+                    "entity": "duration",
+                    "slotName": "duration"
+                }
+            ],
+            "alternatives": [
+                {
+                    "intent": {"intentName": null, "confidenceScore": 0.1},
+                    "slots": []
+                }
+            ]
+        }"#;
+        let result: IntentParserResult = serde_json::from_str(json).unwrap();
+
+        // When
+        let round_tripped_result =
+            protobuf_round_trip::<_, proto::IntentParserResult>(result.clone());
+
+        // Then
+        assert_eq!(result, round_tripped_result);
+        let round_tripped_json = serde_json::to_string(&round_tripped_result).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&round_tripped_json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_slot_values_and_builtin_entities_round_trip() {
+        for kind in BuiltinEntityKind::all() {
+            // Given
+            let values: Vec<SlotValue> = serde_json::from_str(&kind.result_description()).unwrap();
+            let entity = BuiltinEntity {
+                value: "foo".to_string(),
+                range: 0..3,
+                entity: values[0].clone(),
+                alternatives: values[1..].to_vec(),
+                entity_kind: *kind,
+            };
+
+            // When
+            let round_tripped_entity =
+                protobuf_round_trip::<_, proto::BuiltinEntity>(entity.clone());
+
+            // Then
+            assert_eq!(entity, round_tripped_entity);
+            for value in values {
+                assert_eq!(
+                    value.clone(),
+                    protobuf_round_trip::<_, proto::SlotValue>(value)
+                );
+            }
+        }
+        for language in Language::all() {
+            let round_tripped_language = proto::Language::from(*language).try_into();
+            assert_eq!(*language, round_tripped_language.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_unknown_slot_values_and_entity_kinds_round_trip() {
+        // Given
+        let json = r#"{
+            "value": "a fortnight",
+            "range": {"start": 0, "end": 11},
+            "entity": {"kind": "Fortnight", "count": 1, "precision": "Exact"},
+            "alternatives": [{"kind": "Number", "value": 14.0}],
+            "entity_kind": "snips/fortnight"
+        }"#;
+        let entity: LenientBuiltinEntity = crate::lenient::from_str(json).unwrap();
+
+        // When
+        let round_tripped_entity = protobuf_round_trip::<_, proto::BuiltinEntity>(entity.clone());
+
+        // Then
+        assert_eq!(entity, round_tripped_entity);
+        assert_eq!(
+            LenientEntityKind::Unknown("snips/fortnight".to_string()),
+            round_tripped_entity.entity_kind
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json).unwrap(),
+            serde_json::to_value(&round_tripped_entity).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_messages_are_rejected() {
+        // Given
+        let missing_intent = proto::IntentParserResult::default();
+        let unknown_entity = proto::BuiltinEntity {
+            range: Some((0..3).into()),
+            entity: Some(SlotValue::Number(NumberValue { value: 1.0 }).into()),
+            entity_kind: "snips/foo".to_string(),
+            ..Default::default()
+        };
+        let unspecified_grain = proto::SlotValue {
+            value: Some(proto::slot_value::Value::InstantTime(
+                proto::InstantTimeValue {
+                    value: "2019-09-13 20:00:00 +02:00".to_string(),
+                    grain: proto::Grain::Unspecified as i32,
+                    precision: proto::Precision::Exact as i32,
+                },
+            )),
+        };
+
+        // When/Then
+        assert!(IntentParserResult::try_from(missing_intent).is_err());
+        assert!(BuiltinEntity::try_from(unknown_entity).is_err());
+        assert!(SlotValue::try_from(unspecified_grain).is_err());
+        assert!(SlotValue::try_from(proto::SlotValue::default()).is_err());
+    }
+}
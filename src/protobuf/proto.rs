@@ -0,0 +1,4 @@
+//! Protocol Buffers messages of the ontology, generated by `build.rs` from
+//! `platforms/protobuf/ontology.proto`
+
+include!(concat!(env!("OUT_DIR"), "/snips.nlu.ontology.rs"));
@@ -0,0 +1,416 @@
+//! Python bindings of the ontology, built with PyO3
+//!
+//! The classes wrap the Rust types and are added to a Python module by `register`. The
+//! `platforms/python` package builds them as the `snips_nlu_ontology` extension module.
+
+use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
+use crate::entity::gazetteer_entity::BuiltinGazetteerEntityKind;
+use crate::entity::grammar_entity::GrammarEntityKind;
+use crate::errors::OntologyError;
+use crate::language::Language;
+use crate::ontology::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::str::FromStr;
+
+/// Adds the ontology classes to the given Python module
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyLanguage>()?;
+    module.add_class::<PyBuiltinEntityKind>()?;
+    module.add_class::<PyGrammarEntityKind>()?;
+    module.add_class::<PyBuiltinGazetteerEntityKind>()?;
+    module.add_class::<PyIntentParserResult>()?;
+    module.add_class::<PyIntentParserAlternative>()?;
+    module.add_class::<PyIntentClassifierResult>()?;
+    module.add_class::<PySlot>()?;
+    module.add_class::<PySlotValue>()?;
+    Ok(())
+}
+
+impl From<OntologyError> for PyErr {
+    fn from(error: OntologyError) -> PyErr {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+fn value_error<E: ToString>(error: E) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+#[pyclass(name = "Language", module = "snips_nlu_ontology", frozen, eq, hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PyLanguage(pub Language);
+
+#[pymethods]
+impl PyLanguage {
+    #[new]
+    fn new(code: &str) -> PyResult<Self> {
+        Ok(PyLanguage(Language::from_str(code)?))
+    }
+
+    #[staticmethod]
+    fn all() -> Vec<PyLanguage> {
+        Language::all().iter().cloned().map(PyLanguage).collect()
+    }
+
+    #[getter]
+    fn code(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn full_name(&self) -> &'static str {
+        self.0.full_name()
+    }
+
+    fn full_name_in(&self, display_language: PyLanguage) -> &'static str {
+        self.0.full_name_in(display_language.0)
+    }
+
+    fn supported_builtin_entities(&self) -> Vec<PyBuiltinEntityKind> {
+        let entities = self.0.supported_builtin_entities();
+        entities.into_iter().map(PyBuiltinEntityKind).collect()
+    }
+
+    fn supported_grammar_entities(&self) -> Vec<PyGrammarEntityKind> {
+        let entities = self.0.supported_grammar_entities();
+        entities.into_iter().map(PyGrammarEntityKind).collect()
+    }
+
+    fn supported_gazetteer_entities(&self) -> Vec<PyBuiltinGazetteerEntityKind> {
+        let entities = self.0.supported_gazetteer_entities();
+        entities
+            .into_iter()
+            .map(PyBuiltinGazetteerEntityKind)
+            .collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Language('{}')", self.0.to_string())
+    }
+}
+
+macro_rules! py_entity_kind {
+    ($pykindname:ident, $kindname:ident, $pyname:literal) => {
+        #[pyclass(name = $pyname, module = "snips_nlu_ontology", frozen, eq, hash)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $pykindname(pub $kindname);
+
+        #[pymethods]
+        impl $pykindname {
+            #[new]
+            fn new(name: &str) -> PyResult<Self> {
+                Ok($pykindname($kindname::from_str(name)?))
+            }
+
+            #[staticmethod]
+            fn all() -> Vec<$pykindname> {
+                $kindname::all().iter().cloned().map($pykindname).collect()
+            }
+
+            #[staticmethod]
+            fn from_identifier(identifier: &str) -> PyResult<Self> {
+                Ok($pykindname($kindname::from_identifier(identifier)?))
+            }
+
+            #[getter]
+            fn name(&self) -> String {
+                self.0.to_string()
+            }
+
+            fn builtin_kind(&self) -> PyBuiltinEntityKind {
+                PyBuiltinEntityKind(self.0.into_builtin_kind())
+            }
+
+            fn identifier(&self) -> &'static str {
+                self.0.identifier()
+            }
+
+            fn description(&self) -> &'static str {
+                self.0.description()
+            }
+
+            fn description_in(&self, language: PyLanguage) -> &'static str {
+                self.0.description_in(language.0)
+            }
+
+            fn result_description(&self) -> String {
+                self.0.result_description()
+            }
+
+            fn supported_languages(&self) -> Vec<PyLanguage> {
+                let languages = self.0.supported_languages();
+                languages.iter().cloned().map(PyLanguage).collect()
+            }
+
+            fn examples(&self, language: PyLanguage) -> Vec<&'static str> {
+                self.0.examples(language.0).to_vec()
+            }
+
+            fn value_kinds(&self) -> Vec<&'static str> {
+                let value_kinds = self.0.value_kinds().iter();
+                value_kinds.map(|kind| kind.name()).collect()
+            }
+
+            fn __str__(&self) -> &'static str {
+                self.0.identifier()
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}('{}')", $pyname, self.0.to_string())
+            }
+        }
+    };
+}
+
+py_entity_kind!(PyBuiltinEntityKind, BuiltinEntityKind, "BuiltinEntityKind");
+py_entity_kind!(PyGrammarEntityKind, GrammarEntityKind, "GrammarEntityKind");
+py_entity_kind!(
+    PyBuiltinGazetteerEntityKind,
+    BuiltinGazetteerEntityKind,
+    "BuiltinGazetteerEntityKind"
+);
+
+/// Defines a Python class wrapping a serializable ontology type, along with its JSON and dict
+/// conversions and the additional `$methods`
+macro_rules! py_json_class {
+    ($pytypename:ident, $typename:ident, $pyname:literal, { $($methods:tt)* }) => {
+        #[pyclass(name = $pyname, module = "snips_nlu_ontology", frozen, eq)]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $pytypename(pub $typename);
+
+        #[pymethods]
+        impl $pytypename {
+            #[staticmethod]
+            fn from_json(json: &str) -> PyResult<Self> {
+                serde_json::from_str(json).map($pytypename).map_err(value_error)
+            }
+
+            fn to_json(&self) -> PyResult<String> {
+                serde_json::to_string(&self.0).map_err(value_error)
+            }
+
+            #[staticmethod]
+            fn from_dict(dict: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let json_module = PyModule::import_bound(dict.py(), "json")?;
+                let json: String = json_module.call_method1("dumps", (dict,))?.extract()?;
+                Self::from_json(&json)
+            }
+
+            fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                let json_module = PyModule::import_bound(py, "json")?;
+                json_module.call_method1("loads", (self.to_json()?,))
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(format!("{}({})", $pyname, self.to_json()?))
+            }
+
+            $($methods)*
+        }
+    };
+}
+
+py_json_class!(
+    PyIntentParserResult,
+    IntentParserResult,
+    "IntentParserResult",
+    {
+        #[getter]
+        fn input(&self) -> String {
+            self.0.input.clone()
+        }
+
+        #[getter]
+        fn intent(&self) -> PyIntentClassifierResult {
+            PyIntentClassifierResult(self.0.intent.clone())
+        }
+
+        #[getter]
+        fn slots(&self) -> Vec<PySlot> {
+            self.0.slots.iter().cloned().map(PySlot).collect()
+        }
+
+        #[getter]
+        fn alternatives(&self) -> Vec<PyIntentParserAlternative> {
+            let alternatives = self.0.alternatives.iter().cloned();
+            alternatives.map(PyIntentParserAlternative).collect()
+        }
+
+        /// Returns the descriptions of the inconsistencies found in the result, if any
+        fn validate(&self) -> Vec<String> {
+            self.0.validate().iter().map(|i| i.to_string()).collect()
+        }
+    }
+);
+
+py_json_class!(
+    PyIntentParserAlternative,
+    IntentParserAlternative,
+    "IntentParserAlternative",
+    {
+        #[getter]
+        fn intent(&self) -> PyIntentClassifierResult {
+            PyIntentClassifierResult(self.0.intent.clone())
+        }
+
+        #[getter]
+        fn slots(&self) -> Vec<PySlot> {
+            self.0.slots.iter().cloned().map(PySlot).collect()
+        }
+    }
+);
+
+py_json_class!(
+    PyIntentClassifierResult,
+    IntentClassifierResult,
+    "IntentClassifierResult",
+    {
+        #[getter]
+        fn intent_name(&self) -> Option<String> {
+            self.0.intent_name.clone()
+        }
+
+        #[getter]
+        fn confidence_score(&self) -> f32 {
+            self.0.confidence_score
+        }
+    }
+);
+
+py_json_class!(PySlot, Slot, "Slot", {
+    #[getter]
+    fn raw_value(&self) -> String {
+        self.0.raw_value.clone()
+    }
+
+    #[getter]
+    fn value(&self) -> PySlotValue {
+        PySlotValue(self.0.value.clone())
+    }
+
+    #[getter]
+    fn alternatives(&self) -> Vec<PySlotValue> {
+        self.0
+            .alternatives
+            .iter()
+            .cloned()
+            .map(PySlotValue)
+            .collect()
+    }
+
+    #[getter]
+    fn range(&self) -> (usize, usize) {
+        (self.0.range.start, self.0.range.end)
+    }
+
+    #[getter]
+    fn entity(&self) -> String {
+        self.0.entity.clone()
+    }
+
+    #[getter]
+    fn slot_name(&self) -> String {
+        self.0.slot_name.clone()
+    }
+
+    #[getter]
+    fn confidence_score(&self) -> Option<f32> {
+        self.0.confidence_score
+    }
+});
+
+py_json_class!(PySlotValue, SlotValue, "SlotValue", {
+    /// Kind of the slot value, such as "Number" or "InstantTime", as found in its JSON
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.0.kind().name()
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn run_python(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "snips_nlu_ontology").unwrap();
+            register(&module).unwrap();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("ontology", module).unwrap();
+            if let Err(error) = py.run_bound(code, None, Some(&locals)) {
+                error.print(py);
+                panic!("Python assertion failed");
+            }
+        })
+    }
+
+    #[test]
+    fn test_language_and_entity_kinds() {
+        run_python(
+            r#"
+en = ontology.Language("en")
+assert en.code == "en"
+assert en.full_name() == "English"
+assert en in ontology.Language.all()
+
+number = ontology.BuiltinEntityKind.from_identifier("snips/number")
+assert number == ontology.BuiltinEntityKind("Number")
+assert number.identifier() == "snips/number"
+assert number.description()
+assert number.result_description().startswith("[")
+assert en in number.supported_languages()
+assert number in en.supported_builtin_entities()
+
+city = ontology.BuiltinGazetteerEntityKind.from_identifier("snips/city")
+assert city.builtin_kind() == ontology.BuiltinEntityKind("City")
+assert ontology.GrammarEntityKind("Duration").identifier() == "snips/duration"
+
+try:
+    ontology.GrammarEntityKind.from_identifier("snips/city")
+    assert False
+except ValueError as e:
+    assert "snips/city" in str(e)
+"#,
+        );
+    }
+
+    #[test]
+    fn test_intent_parser_result_conversions() {
+        run_python(
+            r#"
+result_dict = {
+    "input": "set the temperature to 21 degrees",
+    "intent": {"intentName": "SetTemperature", "confidenceScore": 0.5},
+    "slots": [
+        {
+            "rawValue": "21 degrees",
+            "value": {"kind": "Temperature", "value": 21.0, "unit": "degree"},
+            "alternatives": [],
+            "range": {"start": 23, "end": 33},
+            "entity": "snips/temperature",
+            "slotName": "temperature",
+        }
+    ],
+    "alternatives": [],
+}
+result = ontology.IntentParserResult.from_dict(result_dict)
+assert result.input == "set the temperature to 21 degrees"
+assert result.intent.intent_name == "SetTemperature"
+slot = result.slots[0]
+assert slot.range == (23, 33)
+assert slot.confidence_score is None
+assert slot.value.kind == "Temperature"
+assert slot.value.to_dict()["value"] == 21.0
+assert result.to_dict() == result_dict
+assert ontology.IntentParserResult.from_json(result.to_json()) == result
+assert result.validate() == []
+"#,
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use crate::errors::*;
+use crate::ontology::*;
+use std::iter::once;
+use std::ops::Range;
+
+/// Unit of the offsets of a range within an input string
+///
+/// The ranges of the ontology types, such as `Slot::range` and `BuiltinEntity::range`, are char
+/// ranges, where a char is a Unicode scalar value. Byte ranges are needed to slice Rust strings,
+/// while UTF-16 ranges are needed on the JVM and in JavaScript.
+///
+/// Units are serialized using their `name`.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum RangeUnit {
+    #[serde(rename = "char")]
+    Char,
+    #[serde(rename = "byte")]
+    Byte,
+    #[serde(rename = "UTF-16")]
+    Utf16,
+}
+
+impl RangeUnit {
+    pub fn all() -> &'static [RangeUnit] {
+        static ALL: &[RangeUnit] = &[RangeUnit::Char, RangeUnit::Byte, RangeUnit::Utf16];
+        ALL
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            RangeUnit::Char => "char",
+            RangeUnit::Byte => "byte",
+            RangeUnit::Utf16 => "UTF-16",
+        }
+    }
+
+    /// Converts a range of the input expressed in this unit into the `to` unit
+    ///
+    /// The conversion fails when the range is not within the input, or when one of its bounds
+    /// falls inside a char, as a byte offset in a multi-byte char or a UTF-16 offset between the
+    /// two halves of a surrogate pair would.
+    pub fn convert_range(
+        self,
+        input: &str,
+        range: &Range<usize>,
+        to: RangeUnit,
+    ) -> OntologyResult<Range<usize>> {
+        let invalid_range = || OntologyError::InvalidRange {
+            range: range.clone(),
+            unit: self,
+        };
+        if range.start > range.end {
+            return Err(invalid_range());
+        }
+        let start = self.byte_offset(input, range.start);
+        let end = self.byte_offset(input, range.end);
+        match (start, end) {
+            (Some(start), Some(end)) => {
+                Ok(to.offset_from_bytes(input, start)..to.offset_from_bytes(input, end))
+            }
+            _ => Err(invalid_range()),
+        }
+    }
+
+    /// Returns the length of the input in this unit
+    pub fn len(self, input: &str) -> usize {
+        self.offset_from_bytes(input, input.len())
+    }
+
+    fn byte_offset(self, input: &str, offset: usize) -> Option<usize> {
+        match self {
+            RangeUnit::Byte => Some(offset).filter(|offset| input.is_char_boundary(*offset)),
+            RangeUnit::Char => input
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain(once(input.len()))
+                .nth(offset),
+            RangeUnit::Utf16 => {
+                let mut utf16_offset = 0;
+                for (index, c) in input.char_indices().chain(once((input.len(), '\0'))) {
+                    if utf16_offset >= offset {
+                        return Some(index).filter(|_| utf16_offset == offset);
+                    }
+                    utf16_offset += c.len_utf16();
+                }
+                None
+            }
+        }
+    }
+
+    fn offset_from_bytes(self, input: &str, byte_offset: usize) -> usize {
+        let prefix = &input[..byte_offset];
+        match self {
+            RangeUnit::Byte => byte_offset,
+            RangeUnit::Char => prefix.chars().count(),
+            RangeUnit::Utf16 => prefix.chars().map(char::len_utf16).sum(),
+        }
+    }
+}
+
+impl IntentParserResult {
+    /// Converts the ranges of the slots, including the ones of the alternatives, from the `from`
+    /// unit into the `to` unit
+    pub fn convert_ranges(self, from: RangeUnit, to: RangeUnit) -> OntologyResult<Self> {
+        let input = self.input;
+        let slots = convert_slot_ranges(&input, self.slots, from, to)?;
+        let alternatives = self
+            .alternatives
+            .into_iter()
+            .map(|alternative| {
+                Ok(IntentParserAlternative {
+                    slots: convert_slot_ranges(&input, alternative.slots, from, to)?,
+                    ..alternative
+                })
+            })
+            .collect::<OntologyResult<_>>()?;
+        Ok(IntentParserResult {
+            input,
+            intent: self.intent,
+            slots,
+            alternatives,
+        })
+    }
+}
+
+fn convert_slot_ranges(
+    input: &str,
+    slots: Vec<Slot>,
+    from: RangeUnit,
+    to: RangeUnit,
+) -> OntologyResult<Vec<Slot>> {
+    slots
+        .into_iter()
+        .map(|slot| {
+            Ok(Slot {
+                range: from.convert_range(input, &slot.range, to)?,
+                ..slot
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_units_are_serialized_using_their_names() {
+        for unit in RangeUnit::all() {
+            let json = serde_json::to_value(unit).unwrap();
+            assert_eq!(unit.name(), json);
+            assert_eq!(*unit, serde_json::from_value::<RangeUnit>(json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_convert_range() {
+        // Given
+        let input = "東京の天気 🌧 in Paris";
+        let char_range = 11..16;
+
+        // When
+        let byte_range = RangeUnit::Char.convert_range(input, &char_range, RangeUnit::Byte);
+        let utf16_range = RangeUnit::Char.convert_range(input, &char_range, RangeUnit::Utf16);
+
+        // Then
+        assert_eq!(Ok(24..29), byte_range);
+        assert_eq!(Ok(12..17), utf16_range);
+        assert_eq!("Paris", &input[24..29]);
+        for from in RangeUnit::all() {
+            for to in RangeUnit::all() {
+                let range = RangeUnit::Char
+                    .convert_range(input, &(6..7), *from)
+                    .unwrap();
+                let converted_range = from.convert_range(input, &range, *to).unwrap();
+                let expected_range = RangeUnit::Char.convert_range(input, &(6..7), *to);
+                assert_eq!(expected_range, Ok(converted_range));
+            }
+        }
+        assert_eq!(16, RangeUnit::Char.len(input));
+        assert_eq!(17, RangeUnit::Utf16.len(input));
+    }
+
+    #[test]
+    fn test_convert_invalid_range() {
+        // Given
+        let input = "東京 🌧";
+
+        // When/Then
+        assert_eq!(
+            Err(OntologyError::InvalidRange {
+                range: 1..3,
+                unit: RangeUnit::Byte
+            }),
+            RangeUnit::Byte.convert_range(input, &(1..3), RangeUnit::Char)
+        );
+        assert_eq!(
+            Err(OntologyError::InvalidRange {
+                range: 3..4,
+                unit: RangeUnit::Utf16
+            }),
+            RangeUnit::Utf16.convert_range(input, &(3..4), RangeUnit::Char)
+        );
+        assert_eq!(
+            Err(OntologyError::InvalidRange {
+                range: 2..5,
+                unit: RangeUnit::Char
+            }),
+            RangeUnit::Char.convert_range(input, &(2..5), RangeUnit::Byte)
+        );
+        assert_eq!(
+            Err(OntologyError::InvalidRange {
+                range: Range { start: 2, end: 1 },
+                unit: RangeUnit::Char
+            }),
+            RangeUnit::Char.convert_range(input, &Range { start: 2, end: 1 }, RangeUnit::Byte)
+        );
+    }
+
+    #[test]
+    fn test_convert_intent_parser_result_ranges() {
+        // Given
+        let input = "明日の東京の天気";
+        let slot = Slot {
+            raw_value: "東京".to_string(),
+            value: SlotValue::City("東京".into()),
+            alternatives: vec![],
+            range: 3..5,
+            entity: "snips/city".to_string(),
+            slot_name: "location".to_string(),
+            confidence_score: None,
+        };
+        let intent = IntentClassifierResult {
+            intent_name: Some("searchWeather".to_string()),
+            confidence_score: 0.9,
+        };
+        let result = IntentParserResult {
+            input: input.to_string(),
+            intent: intent.clone(),
+            slots: vec![slot.clone()],
+            alternatives: vec![IntentParserAlternative {
+                intent,
+                slots: vec![slot],
+            }],
+        };
+
+        // When
+        let converted_result = result
+            .clone()
+            .convert_ranges(RangeUnit::Char, RangeUnit::Byte)
+            .unwrap();
+
+        // Then
+        assert_eq!(9..15, converted_result.slots[0].range);
+        assert_eq!(9..15, converted_result.alternatives[0].slots[0].range);
+        assert_eq!(
+            Ok(result),
+            converted_result.convert_ranges(RangeUnit::Byte, RangeUnit::Char)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! JSON Schema of the serialized ontology types
+//!
+//! The full schema document, returned by `ontology_json_schema`, defines `IntentParserResult`,
+//! `Slot`, `SlotValue` and `BuiltinEntity`, and validates any of them.
+
+use crate::entity::builtin_entity::{BuiltinEntity, BuiltinEntityKind};
+use crate::ontology::*;
+use crate::temperature::TemperatureUnit;
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{
+    InstanceType, Metadata, RootSchema, Schema, SchemaObject, StringValidation, SubschemaValidation,
+};
+use schemars::JsonSchema;
+
+pub const BUILTIN_ENTITY_IDENTIFIER_PATTERN: &str = "^snips/[a-zA-Z]+$";
+
+/// Returns the schema document describing all the ontology types
+pub fn ontology_json_schema() -> RootSchema {
+    let mut generator = SchemaGenerator::new(SchemaSettings::draft07());
+    let root_types = vec![
+        generator.subschema_for::<IntentParserResult>(),
+        generator.subschema_for::<Slot>(),
+        generator.subschema_for::<SlotValue>(),
+        generator.subschema_for::<BuiltinEntity>(),
+    ];
+    RootSchema {
+        meta_schema: generator.settings().meta_schema.clone(),
+        schema: SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("Snips NLU ontology".to_string()),
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(root_types),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        definitions: generator.take_definitions(),
+    }
+}
+
+/// Returns the schema document describing all the ontology types, serialized in JSON
+pub fn ontology_json_schema_string() -> String {
+    serde_json::to_string_pretty(&ontology_json_schema()).unwrap()
+}
+
+/// Schema of the `entity_kind` of a `BuiltinEntity`, which is serialized as its identifier
+pub(crate) fn builtin_entity_kind_schema(_: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(
+            BuiltinEntityKind::all()
+                .iter()
+                .map(|kind| kind.identifier().into())
+                .collect(),
+        ),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(BUILTIN_ENTITY_IDENTIFIER_PATTERN.to_string()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+impl JsonSchema for TemperatureUnit {
+    fn schema_name() -> String {
+        "TemperatureUnit".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            enum_values: Some(
+                TemperatureUnit::all()
+                    .iter()
+                    .map(|unit| unit.name().into())
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn schema_value() -> Value {
+        serde_json::to_value(ontology_json_schema()).unwrap()
+    }
+
+    #[test]
+    fn test_schema_defines_root_types() {
+        // Given
+        let schema = schema_value();
+
+        // When
+        let root_refs = schema["anyOf"].as_array().unwrap();
+
+        // Then
+        let expected_refs = vec![
+            json!({"$ref": "#/definitions/IntentParserResult"}),
+            json!({"$ref": "#/definitions/Slot"}),
+            json!({"$ref": "#/definitions/SlotValue"}),
+            json!({"$ref": "#/definitions/BuiltinEntity"}),
+        ];
+        assert_eq!(&expected_refs, root_refs);
+        assert_eq!("http://json-schema.org/draft-07/schema#", schema["$schema"]);
+    }
+
+    #[test]
+    fn test_slot_value_schema_is_tagged_by_kind() {
+        // Given
+        let schema = schema_value();
+
+        // When
+        let variants = schema["definitions"]["SlotValue"]["oneOf"]
+            .as_array()
+            .unwrap();
+        let kinds = variants
+            .iter()
+            .map(|variant| {
+                let required = variant["required"].as_array().unwrap();
+                assert!(required.contains(&json!("kind")));
+                variant["properties"]["kind"]["enum"][0].clone()
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(15, kinds.len());
+        assert!(kinds.contains(&json!("Custom")));
+        assert!(kinds.contains(&json!("InstantTime")));
+        assert!(kinds.contains(&json!("Region")));
+    }
+
+    #[test]
+    fn test_instant_time_schema_describes_datetime_format() {
+        // Given
+        let schema = schema_value();
+
+        // When
+        let instant_time_schema = schema["definitions"]["SlotValue"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|variant| variant["properties"]["kind"]["enum"][0] == "InstantTime")
+            .unwrap();
+
+        // Then
+        assert!(instant_time_schema["properties"]["value"]["pattern"].is_string());
+    }
+
+    #[test]
+    fn test_entity_kind_schema_uses_identifiers() {
+        // Given
+        let schema = schema_value();
+
+        // When
+        let entity_kind_schema =
+            &schema["definitions"]["BuiltinEntity"]["properties"]["entity_kind"];
+
+        // Then
+        assert_eq!(
+            BUILTIN_ENTITY_IDENTIFIER_PATTERN,
+            entity_kind_schema["pattern"]
+        );
+        let identifiers = entity_kind_schema["enum"].as_array().unwrap();
+        assert_eq!(BuiltinEntityKind::all().len(), identifiers.len());
+        assert!(identifiers.contains(&json!("snips/musicAlbum")));
+    }
+}
@@ -0,0 +1,251 @@
+use crate::errors::*;
+use crate::ontology::*;
+use failure::bail;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// Unit of a `TemperatureValue`, as parsed by `TemperatureValue::parsed_unit`
+///
+/// `Degree` is used when the unit is not specified, as in "twenty degrees".
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Degree,
+}
+
+impl TemperatureUnit {
+    pub fn all() -> &'static [TemperatureUnit] {
+        static ALL: &[TemperatureUnit] = &[
+            TemperatureUnit::Celsius,
+            TemperatureUnit::Fahrenheit,
+            TemperatureUnit::Kelvin,
+            TemperatureUnit::Degree,
+        ];
+        ALL
+    }
+
+    /// Returns the name of the unit, as found in the serialized temperature values
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit",
+            TemperatureUnit::Kelvin => "kelvin",
+            TemperatureUnit::Degree => "degree",
+        }
+    }
+
+    /// Parses a unit leniently, ignoring case and accepting symbols such as "°C"
+    ///
+    /// Returns `None` when the unit is not recognized.
+    pub fn from_lenient_str(unit: &str) -> Option<TemperatureUnit> {
+        match &*unit.trim().to_lowercase() {
+            "celsius" | "°c" | "c" | "℃" | "degree celsius" | "degrees celsius" => {
+                Some(TemperatureUnit::Celsius)
+            }
+            "fahrenheit" | "°f" | "f" | "℉" | "degree fahrenheit" | "degrees fahrenheit" => {
+                Some(TemperatureUnit::Fahrenheit)
+            }
+            "kelvin" | "kelvins" | "k" => Some(TemperatureUnit::Kelvin),
+            "degree" | "degrees" | "°" => Some(TemperatureUnit::Degree),
+            _ => None,
+        }
+    }
+
+    fn to_kelvin(self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => value + 273.15,
+            TemperatureUnit::Fahrenheit => (value - 32.) * 5. / 9. + 273.15,
+            TemperatureUnit::Kelvin | TemperatureUnit::Degree => value,
+        }
+    }
+
+    fn kelvin_to_unit(self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => value - 273.15,
+            TemperatureUnit::Fahrenheit => (value - 273.15) * 9. / 5. + 32.,
+            TemperatureUnit::Kelvin | TemperatureUnit::Degree => value,
+        }
+    }
+}
+
+impl Serialize for TemperatureUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Only the names returned by `TemperatureUnit::name` are accepted, see `from_lenient_str` to
+/// parse units as found in the input
+impl<'de> Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        let unit = String::deserialize(deserializer)?;
+        TemperatureUnit::all()
+            .iter()
+            .find(|u| u.name() == unit)
+            .cloned()
+            .ok_or_else(|| de::Error::custom(format!("unknown temperature unit `{}`", unit)))
+    }
+}
+
+impl TemperatureValue {
+    /// Returns the leniently parsed unit of the temperature, which is `None` when the unit is
+    /// missing or not recognized
+    pub fn parsed_unit(&self) -> Option<TemperatureUnit> {
+        self.unit
+            .as_ref()
+            .and_then(|unit| TemperatureUnit::from_lenient_str(unit))
+    }
+
+    /// Converts the temperature to the given unit
+    ///
+    /// The conversion fails when the unit of the temperature is missing, unrecognized or
+    /// unspecified, unless the target unit is `TemperatureUnit::Degree`.
+    pub fn to_unit(&self, unit: TemperatureUnit) -> Result<TemperatureValue> {
+        let source_unit = self.parsed_unit().unwrap_or(TemperatureUnit::Degree);
+        if source_unit == unit {
+            return Ok(self.clone());
+        }
+        if source_unit == TemperatureUnit::Degree || unit == TemperatureUnit::Degree {
+            bail!(
+                "Cannot convert temperature from {:?} to {:?}",
+                self.unit,
+                unit
+            )
+        }
+        let kelvin = source_unit.to_kelvin(f64::from(self.value));
+        Ok(TemperatureValue {
+            value: unit.kelvin_to_unit(kelvin) as f32,
+            unit: Some(unit.name().to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsed_unit() {
+        // Given
+        let units = vec![
+            Some("celsius"),
+            Some("Celsius"),
+            Some("°C"),
+            Some("fahrenheit"),
+            Some("K"),
+            Some("degree"),
+            Some("foo"),
+            None,
+        ];
+
+        // When
+        let parsed_units = units
+            .into_iter()
+            .map(|unit| {
+                TemperatureValue {
+                    value: 20.0,
+                    unit: unit.map(|u| u.to_string()),
+                }
+                .parsed_unit()
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        let expected_units = vec![
+            Some(TemperatureUnit::Celsius),
+            Some(TemperatureUnit::Celsius),
+            Some(TemperatureUnit::Celsius),
+            Some(TemperatureUnit::Fahrenheit),
+            Some(TemperatureUnit::Kelvin),
+            Some(TemperatureUnit::Degree),
+            None,
+            None,
+        ];
+        assert_eq!(expected_units, parsed_units);
+    }
+
+    #[test]
+    fn test_temperature_unit_ser_de() {
+        // Given
+        let json = r#"["celsius","fahrenheit","kelvin","degree"]"#;
+
+        // When
+        let units: Vec<TemperatureUnit> = serde_json::from_str(json).unwrap();
+        let lenient_unit = serde_json::from_str::<TemperatureUnit>(r#""°C""#);
+
+        // Then
+        assert_eq!(TemperatureUnit::all(), &*units);
+        assert_eq!(json, serde_json::to_string(&units).unwrap());
+        assert!(lenient_unit.is_err());
+    }
+
+    #[test]
+    fn test_temperature_value_ser_de() {
+        // Given
+        let json = r#"{"kind":"Temperature","value":23.0,"unit":"°Ré"}"#;
+        let json_without_unit = r#"{"kind":"Temperature","value":23.0,"unit":null}"#;
+
+        // When
+        let value: SlotValue = serde_json::from_str(json).unwrap();
+        let value_without_unit: SlotValue = serde_json::from_str(json_without_unit).unwrap();
+
+        // Then
+        let expected_value = SlotValue::Temperature(TemperatureValue {
+            value: 23.0,
+            unit: Some("°Ré".to_string()),
+        });
+        let expected_value_without_unit = SlotValue::Temperature(TemperatureValue {
+            value: 23.0,
+            unit: None,
+        });
+        assert_eq!(expected_value, value);
+        assert_eq!(expected_value_without_unit, value_without_unit);
+        assert_eq!(json, serde_json::to_string(&value).unwrap());
+        assert_eq!(
+            json_without_unit,
+            serde_json::to_string(&value_without_unit).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_unit() {
+        // Given
+        let value = TemperatureValue {
+            value: 100.0,
+            unit: Some("°C".to_string()),
+        };
+
+        // When
+        let fahrenheit = value.to_unit(TemperatureUnit::Fahrenheit).unwrap();
+        let kelvin = value.to_unit(TemperatureUnit::Kelvin).unwrap();
+        let celsius = fahrenheit.to_unit(TemperatureUnit::Celsius).unwrap();
+
+        // Then
+        assert_eq!(Some("fahrenheit".to_string()), fahrenheit.unit);
+        assert!((fahrenheit.value - 212.0).abs() < 1e-3);
+        assert!((kelvin.value - 373.15).abs() < 1e-3);
+        assert!((celsius.value - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_to_unit_with_unspecified_unit() {
+        // Given
+        let value = TemperatureValue {
+            value: 20.0,
+            unit: Some("degrees".to_string()),
+        };
+        let value_with_unknown_unit = TemperatureValue {
+            value: 20.0,
+            unit: Some("°Ré".to_string()),
+        };
+
+        // When/Then
+        assert!(value.to_unit(TemperatureUnit::Celsius).is_err());
+        assert!(value_with_unknown_unit
+            .to_unit(TemperatureUnit::Celsius)
+            .is_err());
+        assert_eq!(value, value.to_unit(TemperatureUnit::Degree).unwrap());
+    }
+}
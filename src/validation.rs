@@ -0,0 +1,505 @@
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::ontology::*;
+use crate::range::RangeUnit;
+use std::fmt;
+use std::ops::Range;
+
+/// Inconsistency found when validating an `IntentParserResult`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// Index of the `IntentParserAlternative` in which the issue was found, if any
+    pub alternative_index: Option<usize>,
+    /// Index of the slot in which the issue was found, if any
+    pub slot_index: Option<usize>,
+    pub kind: ValidationIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssueKind {
+    /// The intent confidence score is not in [0, 1]
+    InvalidIntentConfidence(f32),
+    /// The slot confidence score is not in [0, 1]
+    InvalidSlotConfidence(f32),
+    /// The slot range does not fit in the input, whose length is given in chars
+    RangeOutOfBounds {
+        range: Range<usize>,
+        input_length: usize,
+    },
+    /// The slot raw value differs from the input at the slot range
+    RawValueMismatch {
+        raw_value: String,
+        input_value: String,
+    },
+    /// The kind of the slot value is not one of the kinds produced by the slot entity
+    EntityValueMismatch {
+        entity: String,
+        value_kind: SlotValueKind,
+    },
+    /// The kind of a slot alternative differs from the kind of the slot value
+    AlternativeKindMismatch {
+        alternative_index: usize,
+        value_kind: SlotValueKind,
+        alternative_kind: SlotValueKind,
+    },
+    /// A datetime of a time interval is not formatted as "YYYY-MM-DD hh:mm:ss +hh:mm"
+    InvalidDatetime(String),
+    /// The start of a time interval is after its end
+    InvalidTimeInterval { from: String, to: String },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(alternative_index) = self.alternative_index {
+            write!(f, "alternative {}, ", alternative_index)?;
+        }
+        match self.slot_index {
+            Some(slot_index) => write!(f, "slot {}: ", slot_index)?,
+            None => write!(f, "intent: ")?,
+        }
+        match &self.kind {
+            ValidationIssueKind::InvalidIntentConfidence(score)
+            | ValidationIssueKind::InvalidSlotConfidence(score) => {
+                write!(f, "confidence score {} is not in [0, 1]", score)
+            }
+            ValidationIssueKind::RangeOutOfBounds {
+                range,
+                input_length,
+            } => write!(
+                f,
+                "range {:?} is out of the bounds of the input of length {}",
+                range, input_length
+            ),
+            ValidationIssueKind::RawValueMismatch {
+                raw_value,
+                input_value,
+            } => write!(
+                f,
+                "raw value '{}' differs from the input value '{}'",
+                raw_value, input_value
+            ),
+            ValidationIssueKind::EntityValueMismatch { entity, value_kind } => write!(
+                f,
+                "entity '{}' cannot have a value of kind {}",
+                entity,
+                value_kind.name()
+            ),
+            ValidationIssueKind::AlternativeKindMismatch {
+                alternative_index,
+                value_kind,
+                alternative_kind,
+            } => write!(
+                f,
+                "alternative {} is of kind {} whereas the value is of kind {}",
+                alternative_index,
+                alternative_kind.name(),
+                value_kind.name()
+            ),
+            ValidationIssueKind::InvalidDatetime(datetime) => {
+                write!(f, "'{}' is not a valid datetime", datetime)
+            }
+            ValidationIssueKind::InvalidTimeInterval { from, to } => {
+                write!(f, "time interval starts at {} after its end {}", from, to)
+            }
+        }
+    }
+}
+
+impl IntentParserResult {
+    /// Checks the internal consistency of the result and returns the issues found, if any
+    ///
+    /// Slot ranges are expected to be char ranges of the input.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        validate_intent_and_slots(&self.input, &self.intent, &self.slots, None, &mut issues);
+        for (alternative_index, alternative) in self.alternatives.iter().enumerate() {
+            validate_intent_and_slots(
+                &self.input,
+                &alternative.intent,
+                &alternative.slots,
+                Some(alternative_index),
+                &mut issues,
+            );
+        }
+        issues
+    }
+}
+
+fn validate_intent_and_slots(
+    input: &str,
+    intent: &IntentClassifierResult,
+    slots: &[Slot],
+    alternative_index: Option<usize>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if !is_valid_confidence(intent.confidence_score) {
+        issues.push(ValidationIssue {
+            alternative_index,
+            slot_index: None,
+            kind: ValidationIssueKind::InvalidIntentConfidence(intent.confidence_score),
+        });
+    }
+    for (slot_index, slot) in slots.iter().enumerate() {
+        issues.extend(
+            slot_issues(input, slot)
+                .into_iter()
+                .map(|kind| ValidationIssue {
+                    alternative_index,
+                    slot_index: Some(slot_index),
+                    kind,
+                }),
+        );
+    }
+}
+
+fn slot_issues(input: &str, slot: &Slot) -> Vec<ValidationIssueKind> {
+    let mut issues = vec![];
+    if let Some(issue) = range_issue(input, slot) {
+        issues.push(issue);
+    }
+    if let Some(score) = slot.confidence_score {
+        if !is_valid_confidence(score) {
+            issues.push(ValidationIssueKind::InvalidSlotConfidence(score));
+        }
+    }
+    let value_kind = slot.value.kind();
+    if !expected_value_kinds(&slot.entity).contains(&value_kind) {
+        issues.push(ValidationIssueKind::EntityValueMismatch {
+            entity: slot.entity.clone(),
+            value_kind,
+        });
+    }
+    for (alternative_index, alternative) in slot.alternatives.iter().enumerate() {
+        let alternative_kind = alternative.kind();
+        if alternative_kind != value_kind {
+            issues.push(ValidationIssueKind::AlternativeKindMismatch {
+                alternative_index,
+                value_kind,
+                alternative_kind,
+            });
+        }
+    }
+    for value in Some(&slot.value)
+        .into_iter()
+        .chain(slot.alternatives.iter())
+    {
+        if let SlotValue::TimeInterval(interval) = value {
+            issues.extend(time_interval_issue(interval));
+        }
+    }
+    issues
+}
+
+fn range_issue(input: &str, slot: &Slot) -> Option<ValidationIssueKind> {
+    let range = match RangeUnit::Char.convert_range(input, &slot.range, RangeUnit::Byte) {
+        Ok(range) => range,
+        Err(_) => {
+            return Some(ValidationIssueKind::RangeOutOfBounds {
+                range: slot.range.clone(),
+                input_length: RangeUnit::Char.len(input),
+            })
+        }
+    };
+    let input_value = &input[range];
+    if input_value != slot.raw_value {
+        return Some(ValidationIssueKind::RawValueMismatch {
+            raw_value: slot.raw_value.clone(),
+            input_value: input_value.to_string(),
+        });
+    }
+    None
+}
+
+fn time_interval_issue(interval: &TimeIntervalValue) -> Option<ValidationIssueKind> {
+    let (from, to) = match (&interval.from, &interval.to) {
+        (Some(from), Some(to)) => (from, to),
+        _ => return None,
+    };
+    match (timestamp(from), timestamp(to)) {
+        (None, _) => Some(ValidationIssueKind::InvalidDatetime(from.clone())),
+        (_, None) => Some(ValidationIssueKind::InvalidDatetime(to.clone())),
+        (Some(from_timestamp), Some(to_timestamp)) if from_timestamp > to_timestamp => {
+            Some(ValidationIssueKind::InvalidTimeInterval {
+                from: from.clone(),
+                to: to.clone(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn is_valid_confidence(score: f32) -> bool {
+    (0.0..=1.0).contains(&score)
+}
+
+/// Kinds of the slot values of an entity, custom entities having custom values
+fn expected_value_kinds(entity: &str) -> &'static [SlotValueKind] {
+    match BuiltinEntityKind::from_identifier(entity) {
+        Ok(kind) => kind.value_kinds(),
+        Err(_) => &[SlotValueKind::Custom],
+    }
+}
+
+/// Parses a datetime formatted as "YYYY-MM-DD hh:mm:ss +hh:mm" into a UTC timestamp in seconds
+fn timestamp(datetime: &str) -> Option<i64> {
+    let bytes = datetime.as_bytes();
+    if bytes.len() != 26 || !bytes.is_ascii() {
+        return None;
+    }
+    let number = |range: Range<usize>| datetime[range].parse::<i64>().ok();
+    let separators = [
+        (4, b'-'),
+        (7, b'-'),
+        (10, b' '),
+        (13, b':'),
+        (16, b':'),
+        (19, b' '),
+        (23, b':'),
+    ];
+    if separators.iter().any(|(i, c)| bytes[*i] != *c) {
+        return None;
+    }
+    let is_digit_position = |i: &usize| *i != 20 && separators.iter().all(|(j, _)| i != j);
+    if !(0..bytes.len())
+        .filter(is_digit_position)
+        .all(|i| bytes[i].is_ascii_digit())
+    {
+        return None;
+    }
+    let offset_sign = match bytes[20] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hours, minutes, seconds) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    let offset = offset_sign * (number(21..23)? * 3600 + number(24..26)? * 60);
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 59
+    {
+        return None;
+    }
+    let local_seconds =
+        days_from_civil(year, month, day) * 86400 + hours * 3600 + minutes * 60 + seconds;
+    Some(local_seconds - offset)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let shifted_month = (month + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn city_slot(input: &str, range: Range<usize>) -> Slot {
+        let raw_value = input.chars().skip(range.start).take(range.len()).collect();
+        Slot {
+            raw_value,
+            value: SlotValue::City("Paris".into()),
+            alternatives: vec![],
+            range,
+            entity: "snips/city".to_string(),
+            slot_name: "location".to_string(),
+            confidence_score: Some(0.8),
+        }
+    }
+
+    fn intent_parser_result(input: &str, slots: Vec<Slot>) -> IntentParserResult {
+        IntentParserResult {
+            input: input.to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("searchWeather".to_string()),
+                confidence_score: 0.9,
+            },
+            slots,
+            alternatives: vec![],
+        }
+    }
+
+    #[test]
+    fn test_valid_result_has_no_issues() {
+        // Given
+        let input = "météo à Paris";
+        let result = intent_parser_result(input, vec![city_slot(input, 8..13)]);
+
+        // When
+        let issues = result.validate();
+
+        // Then
+        assert_eq!(Vec::<ValidationIssue>::new(), issues);
+    }
+
+    #[test]
+    fn test_range_and_raw_value_issues() {
+        // Given
+        let input = "météo à Paris";
+        let mut mismatching_slot = city_slot(input, 8..13);
+        mismatching_slot.raw_value = "Lyon".to_string();
+        let mut out_of_bounds_slot = city_slot(input, 8..13);
+        out_of_bounds_slot.range = 8..16;
+        let mut reversed_slot = city_slot(input, 8..13);
+        reversed_slot.range = Range { start: 13, end: 8 };
+        let result = intent_parser_result(
+            input,
+            vec![mismatching_slot, out_of_bounds_slot, reversed_slot],
+        );
+
+        // When
+        let issues = result.validate();
+
+        // Then
+        let expected_kinds = vec![
+            ValidationIssueKind::RawValueMismatch {
+                raw_value: "Lyon".to_string(),
+                input_value: "Paris".to_string(),
+            },
+            ValidationIssueKind::RangeOutOfBounds {
+                range: 8..16,
+                input_length: 13,
+            },
+            ValidationIssueKind::RangeOutOfBounds {
+                range: Range { start: 13, end: 8 },
+                input_length: 13,
+            },
+        ];
+        assert_eq!(
+            expected_kinds,
+            issues.into_iter().map(|i| i.kind).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_value_kind_issues() {
+        // Given
+        let input = "set it to 3 in 2 hours";
+        let mut number_slot = city_slot(input, 10..11);
+        number_slot.entity = "snips/number".to_string();
+        number_slot.alternatives = vec![SlotValue::Number(NumberValue { value: 3.0 })];
+        let mut custom_slot = city_slot(input, 15..22);
+        custom_slot.entity = "duration".to_string();
+        custom_slot.value = SlotValue::Custom("2 hours".into());
+        let result = intent_parser_result(input, vec![number_slot, custom_slot]);
+
+        // When
+        let issues = result.validate();
+
+        // Then
+        let expected_issues = vec![
+            ValidationIssue {
+                alternative_index: None,
+                slot_index: Some(0),
+                kind: ValidationIssueKind::EntityValueMismatch {
+                    entity: "snips/number".to_string(),
+                    value_kind: SlotValueKind::City,
+                },
+            },
+            ValidationIssue {
+                alternative_index: None,
+                slot_index: Some(0),
+                kind: ValidationIssueKind::AlternativeKindMismatch {
+                    alternative_index: 0,
+                    value_kind: SlotValueKind::City,
+                    alternative_kind: SlotValueKind::Number,
+                },
+            },
+        ];
+        assert_eq!(expected_issues, issues);
+    }
+
+    #[test]
+    fn test_confidence_and_time_interval_issues() {
+        // Given
+        let input = "from 10am to 9am";
+        let mut slot = city_slot(input, 0..input.len());
+        slot.entity = "snips/datetime".to_string();
+        slot.confidence_score = Some(1.5);
+        slot.value = SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2019-01-01 10:00:00 +01:00".to_string()),
+            to: Some("2019-01-01 09:00:00 +00:00".to_string()),
+        });
+        slot.alternatives = vec![SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2019-01-01 10:00:00 +00:00".to_string()),
+            to: Some("2019-01-01 09:00:00 +00:00".to_string()),
+        })];
+        let mut result = intent_parser_result(input, vec![]);
+        result.alternatives = vec![IntentParserAlternative {
+            intent: IntentClassifierResult {
+                intent_name: None,
+                confidence_score: -0.1,
+            },
+            slots: vec![slot],
+        }];
+
+        // When
+        let issues = result.validate();
+
+        // Then
+        let expected_kinds = vec![
+            ValidationIssueKind::InvalidIntentConfidence(-0.1),
+            ValidationIssueKind::InvalidSlotConfidence(1.5),
+            ValidationIssueKind::InvalidTimeInterval {
+                from: "2019-01-01 10:00:00 +00:00".to_string(),
+                to: "2019-01-01 09:00:00 +00:00".to_string(),
+            },
+        ];
+        assert!(issues.iter().all(|i| i.alternative_index == Some(0)));
+        assert_eq!(
+            expected_kinds,
+            issues.into_iter().map(|i| i.kind).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_timestamp() {
+        // Given
+        let valid_datetimes = vec![
+            ("1970-01-01 00:00:00 +00:00", 0),
+            ("2019-01-01 10:00:00 +01:00", 1_546_333_200),
+            ("2020-02-29 23:59:59 -02:30", 1_583_029_799),
+        ];
+        let invalid_datetimes = vec![
+            "2019-02-29 00:00:00 +00:00",
+            "2019-02-31 00:00:00 +00:00",
+            "2019-04-31 00:00:00 +00:00",
+            "2019-01-01 00:00:60 +00:00",
+            "2019-01-01 24:00:00 +00:00",
+            "2019-13-01 00:00:00 +00:00",
+            "+019-01-01 00:00:00 +00:00",
+            "2019-01-01 00:00:00 00:00",
+            "2019-01-01T00:00:00 +00:00",
+        ];
+
+        // When/Then
+        for (datetime, expected_timestamp) in valid_datetimes {
+            assert_eq!(
+                Some(expected_timestamp),
+                timestamp(datetime),
+                "{}",
+                datetime
+            );
+        }
+        for datetime in invalid_datetimes {
+            assert_eq!(None, timestamp(datetime), "{}", datetime);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! WebAssembly bindings of the ontology, built with wasm-bindgen
+//!
+//! Languages and entity kinds are exchanged with JavaScript as their codes and identifiers, while
+//! intent parser results are exchanged as JSON strings.
+
+use crate::entity::builtin_entity::BuiltinEntityKind;
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::IntentParserResult;
+use crate::range::RangeUnit;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+fn js_error<E: ToString>(error: E) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn parse(json: &str) -> Result<IntentParserResult> {
+    Ok(serde_json::from_str(json)?)
+}
+
+fn range_unit(name: &str) -> std::result::Result<RangeUnit, JsError> {
+    RangeUnit::all()
+        .iter()
+        .find(|unit| unit.name() == name)
+        .cloned()
+        .ok_or_else(|| JsError::new(&format!("Unknown range unit: {}", name)))
+}
+
+/// Parses an intent parser result and returns its normalized JSON
+#[wasm_bindgen(js_name = parseIntentParserResult)]
+pub fn parse_intent_parser_result(json: &str) -> std::result::Result<String, JsError> {
+    let result = parse(json).map_err(js_error)?;
+    serde_json::to_string(&result).map_err(js_error)
+}
+
+/// Parses an intent parser result and returns its indented JSON
+#[wasm_bindgen(js_name = prettyPrintIntentParserResult)]
+pub fn pretty_print_intent_parser_result(json: &str) -> std::result::Result<String, JsError> {
+    let result = parse(json).map_err(js_error)?;
+    serde_json::to_string_pretty(&result).map_err(js_error)
+}
+
+/// Returns the reasons why the JSON is not a valid and consistent intent parser result, if any
+#[wasm_bindgen(js_name = validateIntentParserResult)]
+pub fn validate_intent_parser_result(json: &str) -> Vec<String> {
+    match parse(json) {
+        Ok(result) => result.validate().iter().map(|i| i.to_string()).collect(),
+        Err(error) => vec![error.to_string()],
+    }
+}
+
+/// Converts the slot ranges of an intent parser result between the "char", "byte" and "UTF-16"
+/// units, JavaScript strings being indexed in UTF-16 code units
+#[wasm_bindgen(js_name = convertIntentParserResultRanges)]
+pub fn convert_intent_parser_result_ranges(
+    json: &str,
+    from: &str,
+    to: &str,
+) -> std::result::Result<String, JsError> {
+    let result = parse(json).map_err(js_error)?;
+    let result = result
+        .convert_ranges(range_unit(from)?, range_unit(to)?)
+        .map_err(js_error)?;
+    serde_json::to_string(&result).map_err(js_error)
+}
+
+/// Returns the codes of all the supported languages
+#[wasm_bindgen]
+pub fn languages() -> Vec<String> {
+    Language::all().iter().map(|l| l.to_string()).collect()
+}
+
+/// Returns the identifiers of all the builtin entities
+#[wasm_bindgen(js_name = builtinEntityIdentifiers)]
+pub fn builtin_entity_identifiers() -> Vec<String> {
+    let kinds = BuiltinEntityKind::all().iter();
+    kinds.map(|kind| kind.identifier().to_string()).collect()
+}
+
+#[wasm_bindgen(js_name = BuiltinEntityKind)]
+pub struct WasmBuiltinEntityKind(BuiltinEntityKind);
+
+#[wasm_bindgen(js_class = BuiltinEntityKind)]
+impl WasmBuiltinEntityKind {
+    #[wasm_bindgen(js_name = fromIdentifier)]
+    pub fn from_identifier(
+        identifier: &str,
+    ) -> std::result::Result<WasmBuiltinEntityKind, JsError> {
+        BuiltinEntityKind::from_identifier(identifier)
+            .map(WasmBuiltinEntityKind)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn identifier(&self) -> String {
+        self.0.identifier().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0.to_string()
+    }
+
+    pub fn description(&self) -> String {
+        self.0.description().to_string()
+    }
+
+    #[wasm_bindgen(js_name = descriptionIn)]
+    pub fn description_in(&self, language: &str) -> std::result::Result<String, JsError> {
+        let language = Language::from_str(language).map_err(js_error)?;
+        Ok(self.0.description_in(language).to_string())
+    }
+
+    #[wasm_bindgen(js_name = resultDescription)]
+    pub fn result_description(&self) -> String {
+        self.0.result_description()
+    }
+
+    #[wasm_bindgen(js_name = supportedLanguages)]
+    pub fn supported_languages(&self) -> Vec<String> {
+        let languages = self.0.supported_languages().iter();
+        languages.map(|l| l.to_string()).collect()
+    }
+
+    pub fn examples(&self, language: &str) -> std::result::Result<Vec<String>, JsError> {
+        let language = Language::from_str(language).map_err(js_error)?;
+        let examples = self.0.examples(language).iter();
+        Ok(examples.map(|e| e.to_string()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULT_JSON: &str = r#"{
+        "input": "what is the weather in Paris",
+        "intent": {"intentName": "searchWeather", "confidenceScore": 0.9},
+        "slots": [{
+            "rawValue": "Paris",
+            "value": {"kind": "City", "value": "Paris"},
+            "range": {"start": 23, "end": 28},
+            "entity": "snips/city",
+            "slotName": "location"
+        }]
+    }"#;
+
+    #[test]
+    fn test_parse_intent_parser_result() {
+        // When
+        let json = parse_intent_parser_result(RESULT_JSON).unwrap();
+        let pretty_json = pretty_print_intent_parser_result(RESULT_JSON).unwrap();
+
+        // Then
+        let expected_result: IntentParserResult = serde_json::from_str(RESULT_JSON).unwrap();
+        assert_eq!(expected_result, serde_json::from_str(&json).unwrap());
+        assert_eq!(expected_result, serde_json::from_str(&pretty_json).unwrap());
+        assert!(json.contains(r#""alternatives":[]"#));
+        assert!(pretty_json.contains('\n'));
+    }
+
+    #[test]
+    fn test_validate_intent_parser_result() {
+        // Given
+        let invalid_json = r#"{"input": "hello", "slots": []}"#;
+
+        // When
+        let errors = validate_intent_parser_result(RESULT_JSON);
+        let invalid_errors = validate_intent_parser_result(invalid_json);
+
+        // Then
+        assert!(errors.is_empty());
+        assert_eq!(1, invalid_errors.len());
+        assert!(invalid_errors[0].contains("intent"));
+    }
+
+    #[test]
+    fn test_convert_intent_parser_result_ranges() {
+        // Given
+        let json = RESULT_JSON
+            .replace("what is", "🌧 what is")
+            .replace(r#""start": 23, "end": 28"#, r#""start": 25, "end": 30"#);
+
+        // When
+        let converted_json = convert_intent_parser_result_ranges(&json, "char", "UTF-16").unwrap();
+
+        // Then
+        let converted_result: IntentParserResult = serde_json::from_str(&converted_json).unwrap();
+        assert_eq!(26..31, converted_result.slots[0].range);
+    }
+
+    #[test]
+    fn test_builtin_entity_kind_metadata() {
+        // When
+        let kind = WasmBuiltinEntityKind::from_identifier("snips/musicAlbum").unwrap();
+
+        // Then
+        assert_eq!("snips/musicAlbum", kind.identifier());
+        assert_eq!("MusicAlbum", kind.name());
+        assert_eq!(
+            BuiltinEntityKind::MusicAlbum.description(),
+            kind.description()
+        );
+        assert!(kind.supported_languages().contains(&"en".to_string()));
+        assert!(!kind.examples("en").unwrap().is_empty());
+        assert_eq!(
+            BuiltinEntityKind::all().len(),
+            builtin_entity_identifiers().len()
+        );
+        assert_eq!(Language::all().len(), languages().len());
+    }
+}